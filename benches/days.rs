@@ -0,0 +1,58 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use std::hint::black_box;
+
+use aoc24::registry::DAYS;
+use aoc24::utils::{get_input, is_single_star_day};
+
+const INPUTS_DIR: &str = "resources/inputs";
+const SAMPLES_DIR: &str = "resources/samples";
+
+// The day's input, or its first sample when the input isn't checked in.
+fn bench_input(day_number: i32) -> Option<(String, String)> {
+    match get_input(day_number, INPUTS_DIR) {
+        Ok(input) => Some((format!("day{day_number}"), input)),
+        Err(_) => std::fs::read_to_string(format!("{SAMPLES_DIR}/day{day_number}-1.txt"))
+            .ok()
+            .map(|input| (format!("day{day_number}-sample"), input)),
+    }
+}
+
+fn days(c: &mut Criterion) {
+    for day in DAYS.iter() {
+        let parser = match day.parser {
+            Some(parser) => parser,
+            None => continue,
+        };
+        let (name, input) = match bench_input(day.number) {
+            Some(input) => input,
+            None => {
+                eprintln!(
+                    "skipping day {}: no input in {INPUTS_DIR} or {SAMPLES_DIR}",
+                    day.number
+                );
+                continue;
+            }
+        };
+        let puzzle = match parser(input.clone()) {
+            Ok(puzzle) => puzzle,
+            Err(msg) => panic!("cannot parse day {} input: {msg}", day.number),
+        };
+
+        let mut group = c.benchmark_group(name);
+        group.bench_function("parse", |b| {
+            b.iter_batched(|| input.clone(), parser, BatchSize::SmallInput)
+        });
+        group.bench_function("one_star", |b| {
+            b.iter(|| black_box(&puzzle).one_star_answer())
+        });
+        if !is_single_star_day(day.number) {
+            group.bench_function("two_star", |b| {
+                b.iter(|| black_box(&puzzle).two_star_answer())
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,135 @@
+use std::fmt::Write;
+use std::path::Path;
+
+const SAMPLES_DIR: &str = "resources/samples";
+const INPUTS_DIR: &str = "resources/inputs";
+
+struct Case {
+    day_number: i32,
+    name: String,
+    path: String,
+    part: i32,
+    expected: String,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={SAMPLES_DIR}");
+    println!("cargo:rerun-if-changed={INPUTS_DIR}");
+
+    let mut cases = sample_cases();
+    cases.extend(input_cases());
+    cases.sort_by(|a, b| (a.day_number, &a.name).cmp(&(b.day_number, &b.name)));
+
+    let mut code = String::new();
+    for (i, case) in cases.iter().enumerate() {
+        if i == 0 || cases[i - 1].day_number != case.day_number {
+            writeln!(code, "mod day{} {{", case.day_number).unwrap();
+        }
+        writeln!(
+            code,
+            "    #[test]\n    pub fn {}() {{\n        super::check_answer({}, {:?}, {}, {:?});\n    }}",
+            case.name, case.day_number, case.path, case.part, case.expected
+        )
+        .unwrap();
+        if cases.get(i + 1).map(|next| next.day_number) != Some(case.day_number) {
+            writeln!(code, "}}").unwrap();
+        }
+    }
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    std::fs::write(Path::new(&out_dir).join("answer_tests.rs"), code)
+        .expect("cannot write the generated answer tests");
+}
+
+fn sample_cases() -> Vec<Case> {
+    let mut cases = Vec::new();
+
+    for file_name in file_names(SAMPLES_DIR) {
+        let (day_number, sample_name) = match file_name
+            .strip_prefix("day")
+            .and_then(|name| name.strip_suffix(".txt"))
+            .and_then(|name| name.split_once('-'))
+            .and_then(|(day, name)| Some((day.parse::<i32>().ok()?, name)))
+        {
+            Some(sample) => sample,
+            None => continue,
+        };
+        let answers_path = format!("{SAMPLES_DIR}/answers/{file_name}");
+        let answers = match std::fs::read_to_string(&answers_path) {
+            Ok(answers) => answers,
+            Err(_) => {
+                println!("cargo:warning=no expected answers for {SAMPLES_DIR}/{file_name}");
+                continue;
+            }
+        };
+
+        for (key, expected) in answers.lines().filter_map(|line| line.split_once('=')) {
+            let (part, part_name) = match key.trim() {
+                "one_star" => (1, "one_star"),
+                "two_star" => (2, "two_star"),
+                _ => continue,
+            };
+            cases.push(Case {
+                day_number,
+                name: format!("sample_{}_{part_name}", identifier(sample_name)),
+                path: format!("{SAMPLES_DIR}/{file_name}"),
+                part,
+                expected: expected.trim().to_string(),
+            });
+        }
+    }
+    cases
+}
+
+fn input_cases() -> Vec<Case> {
+    let mut cases = Vec::new();
+
+    for file_name in file_names(INPUTS_DIR) {
+        let day_number = match file_name
+            .strip_prefix("day")
+            .and_then(|name| name.strip_suffix(".txt"))
+            .and_then(|day| day.parse::<i32>().ok())
+        {
+            Some(day_number) => day_number,
+            None => continue,
+        };
+        let answers = std::fs::read_to_string(format!("{INPUTS_DIR}/answers/{file_name}"))
+            .unwrap_or_default();
+
+        let answers = answers
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty());
+        for ((part, part_name), expected) in
+            [(1, "one_star"), (2, "two_star")].into_iter().zip(answers)
+        {
+            cases.push(Case {
+                day_number,
+                name: format!("input_{part_name}"),
+                path: format!("{INPUTS_DIR}/{file_name}"),
+                part,
+                expected: expected.to_string(),
+            });
+        }
+    }
+    cases
+}
+
+fn file_names(dir: &str) -> Vec<String> {
+    std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect()
+}
+
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            c if c.is_ascii_alphanumeric() => c.to_ascii_lowercase(),
+            _ => '_',
+        })
+        .collect()
+}
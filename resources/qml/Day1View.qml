@@ -0,0 +1,122 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+import AOCSolutions
+
+Item {
+    id: root
+    property string inputsDir
+
+    LocationListsView {
+        id: view
+        onBreakdown_changed: {
+            leftHistogram.requestPaint()
+            rightHistogram.requestPaint()
+        }
+    }
+
+    component Histogram: Canvas {
+        property var bins
+        property color color
+
+        Layout.fillWidth: true
+        Layout.preferredHeight: 120
+
+        onPaint: {
+            var ctx = getContext("2d")
+            ctx.clearRect(0, 0, width, height)
+            if (!bins || bins.length == 0) {
+                return
+            }
+
+            var highest = Math.max.apply(null, bins)
+            var barWidth = width / bins.length
+            ctx.fillStyle = color
+            for (var i = 0; i < bins.length; i++) {
+                var barHeight = highest > 0 ? bins[i] / highest * height : 0
+                ctx.fillRect(i * barWidth + 1, height - barHeight, barWidth - 2, barHeight)
+            }
+        }
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: 20
+
+        RowLayout {
+            Button {
+                text: "Load day 1 input"
+                onClicked: {
+                    view.load(root.inputsDir)
+                }
+            }
+
+            Label {
+                text: view.error_msg.length > 0 ? view.error_msg
+                    : "Values from " + view.histogram_min + " to " + view.histogram_max
+            }
+        }
+
+        Label {
+            text: "Left column"
+        }
+        Histogram {
+            id: leftHistogram
+            bins: view.left_histogram
+            color: "#1565c0"
+        }
+
+        Label {
+            text: "Right column"
+        }
+        Histogram {
+            id: rightHistogram
+            bins: view.right_histogram
+            color: "#ef6c00"
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+
+            ColumnLayout {
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+
+                Label {
+                    text: "Largest distances"
+                    font.bold: true
+                }
+                ListView {
+                    Layout.fillWidth: true
+                    Layout.fillHeight: true
+                    clip: true
+                    model: view.top_distances
+                    delegate: Label {
+                        text: modelData
+                    }
+                }
+            }
+
+            ColumnLayout {
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+
+                Label {
+                    text: "Largest similarity contributions"
+                    font.bold: true
+                }
+                ListView {
+                    Layout.fillWidth: true
+                    Layout.fillHeight: true
+                    clip: true
+                    model: view.top_similarities
+                    delegate: Label {
+                        text: modelData
+                    }
+                }
+            }
+        }
+    }
+}
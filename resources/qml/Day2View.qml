@@ -0,0 +1,111 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+import AOCSolutions
+
+Item {
+    id: root
+    property string inputsDir
+
+    ReportsView {
+        id: view
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: 20
+
+        RowLayout {
+            Button {
+                text: "Load day 2 input"
+                onClicked: {
+                    view.load(root.inputsDir)
+                }
+            }
+
+            ComboBox {
+                model: view.filter_names()
+                onActivated: {
+                    view.filter = currentText
+                }
+            }
+
+            Label {
+                text: view.error_msg.length > 0 ? view.error_msg : view.report_count + " reports"
+            }
+        }
+
+        ListView {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+            model: view.reports
+            ScrollBar.vertical: ScrollBar {}
+
+            delegate: RowLayout {
+                width: ListView.view.width
+                height: 28
+                spacing: 12
+
+                Label {
+                    text: "#" + number
+                    Layout.preferredWidth: 50
+                }
+
+                Canvas {
+                    id: sparkline
+                    Layout.preferredWidth: 120
+                    Layout.fillHeight: true
+                    property var values: levels
+                    property int marked: level_index
+
+                    onValuesChanged: requestPaint()
+
+                    onPaint: {
+                        var ctx = getContext("2d")
+                        ctx.clearRect(0, 0, width, height)
+                        if (values.length < 2) {
+                            return
+                        }
+
+                        var min = Math.min.apply(null, values)
+                        var max = Math.max.apply(null, values)
+                        var range = Math.max(max - min, 1)
+                        var step = (width - 4) / (values.length - 1)
+                        var px = function(i) { return 2 + i * step }
+                        var py = function(i) { return height - 2 - (values[i] - min) / range * (height - 4) }
+
+                        ctx.strokeStyle = category == "safe" ? "#2e7d32"
+                            : category == "saved by removing" ? "#f9a825" : "#c62828"
+                        ctx.lineWidth = 1.5
+                        ctx.beginPath()
+                        ctx.moveTo(px(0), py(0))
+                        for (var i = 1; i < values.length; i++) {
+                            ctx.lineTo(px(i), py(i))
+                        }
+                        ctx.stroke()
+
+                        if (marked >= 0) {
+                            ctx.fillStyle = "black"
+                            ctx.beginPath()
+                            ctx.arc(px(marked), py(marked), 3, 0, 2 * Math.PI)
+                            ctx.fill()
+                        }
+                    }
+                }
+
+                Label {
+                    text: levels.join(" ")
+                    Layout.preferredWidth: 200
+                    elide: Text.ElideRight
+                }
+
+                Label {
+                    text: diagnostic
+                    Layout.fillWidth: true
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+import AOCSolutions
+
+Item {
+    id: root
+    property string inputsDir
+
+    CorruptedMemoryView {
+        id: view
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: 20
+
+        RowLayout {
+            Button {
+                text: "Load day 3 input"
+                onClicked: {
+                    view.load(root.inputsDir)
+                }
+            }
+
+            Label {
+                text: view.error_msg.length > 0 ? view.error_msg
+                    : view.enabled_mul_count + " of " + view.mul_count + " mul instructions enabled"
+            }
+        }
+
+        ScrollView {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+
+            TextArea {
+                id: memory
+                property string hoveredDescription
+
+                readOnly: true
+                wrapMode: TextEdit.WrapAnywhere
+                textFormat: TextEdit.RichText
+                font.family: "monospace"
+                text: view.html
+
+                onLinkHovered: (link) => {
+                    hoveredDescription = link.length > 0 ? view.describe_token(parseInt(link)) : ""
+                }
+
+                ToolTip.visible: hoveredDescription.length > 0
+                ToolTip.text: hoveredDescription
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+import AOCSolutions
+
+Item {
+    id: root
+    property string inputsDir
+    property int cellSize: 14
+
+    WordSearchView {
+        id: view
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: 20
+
+        RowLayout {
+            Button {
+                text: "Load day 4 input"
+                onClicked: {
+                    view.load(root.inputsDir)
+                    canvas.requestPaint()
+                }
+            }
+
+            ComboBox {
+                model: view.filter_names()
+                onActivated: {
+                    view.filter = currentText
+                    canvas.requestPaint()
+                }
+            }
+
+            Label {
+                text: view.error_msg.length > 0 ? view.error_msg : view.match_count + " matches"
+            }
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+
+            ScrollView {
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                clip: true
+                contentWidth: canvas.width
+                contentHeight: canvas.height
+
+                Canvas {
+                    id: canvas
+                    width: view.grid_width * root.cellSize
+                    height: view.grid_height * root.cellSize
+
+                    onPaint: {
+                        var ctx = getContext("2d")
+                        ctx.clearRect(0, 0, width, height)
+                        ctx.font = (root.cellSize - 2) + "px monospace"
+                        ctx.textAlign = "center"
+                        ctx.textBaseline = "middle"
+
+                        for (var i = 0; i < view.characters.length; i++) {
+                            var x = (i % view.grid_width) * root.cellSize
+                            var y = Math.floor(i / view.grid_width) * root.cellSize
+                            var hits = view.highlights[i]
+                            if (hits > 0) {
+                                ctx.fillStyle = Qt.rgba(1.0, 0.6, 0.0, Math.min(0.3 + 0.2 * hits, 1.0))
+                                ctx.fillRect(x, y, root.cellSize, root.cellSize)
+                            }
+                            ctx.fillStyle = hits > 0 ? "black" : "gray"
+                            ctx.fillText(view.characters[i], x + root.cellSize / 2, y + root.cellSize / 2)
+                        }
+                    }
+
+                    MouseArea {
+                        anchors.fill: parent
+                        onClicked: (mouse) => {
+                            view.select_cell(Math.floor(mouse.x / root.cellSize),
+                                             Math.floor(mouse.y / root.cellSize))
+                        }
+                    }
+                }
+            }
+
+            ListView {
+                Layout.preferredWidth: 260
+                Layout.fillHeight: true
+                clip: true
+                model: view.selected_matches
+
+                delegate: Label {
+                    text: modelData
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+import AOCSolutions
+
+Item {
+    id: root
+    property string inputsDir
+    property int cellSize: 8
+
+    FramePlayer {
+        id: player
+        onFrame_changed: canvas.requestPaint()
+    }
+
+    Timer {
+        id: timer
+        interval: 1000 / speed.value
+        repeat: true
+        onTriggered: {
+            if (player.current >= player.frame_count - 1) {
+                stop()
+            } else {
+                player.current = player.current + 1
+            }
+        }
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: 20
+
+        RowLayout {
+            ComboBox {
+                id: dayPicker
+                model: player.recorded_days()
+                displayText: "Day " + currentText
+            }
+
+            Button {
+                text: "Load"
+                enabled: dayPicker.currentIndex >= 0
+                onClicked: {
+                    timer.stop()
+                    player.load(parseInt(dayPicker.currentText), root.inputsDir)
+                }
+            }
+
+            Label {
+                text: player.error_msg.length > 0 ? player.error_msg
+                    : player.frame_count > 0 ? player.label + " (" + (player.current + 1) + "/" + player.frame_count + ")"
+                    : ""
+            }
+        }
+
+        RowLayout {
+            enabled: player.frame_count > 0
+
+            Button {
+                text: "⏮"
+                onClicked: player.current = player.current - 1
+            }
+
+            Button {
+                text: timer.running ? "⏸" : "▶"
+                onClicked: {
+                    if (timer.running) {
+                        timer.stop()
+                    } else {
+                        if (player.current >= player.frame_count - 1) {
+                            player.current = 0
+                        }
+                        timer.start()
+                    }
+                }
+            }
+
+            Button {
+                text: "⏭"
+                onClicked: player.current = player.current + 1
+            }
+
+            Slider {
+                Layout.fillWidth: true
+                from: 0
+                to: Math.max(player.frame_count - 1, 0)
+                stepSize: 1
+                value: player.current
+                onMoved: player.current = value
+            }
+
+            Label {
+                text: "Frames per second"
+            }
+
+            SpinBox {
+                id: speed
+                from: 1
+                to: 60
+                value: 10
+            }
+        }
+
+        ScrollView {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+            contentWidth: canvas.width
+            contentHeight: canvas.height
+
+            Canvas {
+                id: canvas
+                width: player.grid_width * root.cellSize
+                height: player.grid_height * root.cellSize
+
+                onPaint: {
+                    var ctx = getContext("2d")
+                    ctx.clearRect(0, 0, width, height)
+
+                    for (var i = 0; i < player.cells.length; i++) {
+                        var cell = player.cells[i]
+                        if (cell == ".") {
+                            continue
+                        }
+                        ctx.fillStyle = cell == "#" ? "#424242" : cell == "@" ? "#d32f2f" : "#ffa000"
+                        ctx.fillRect((i % player.grid_width) * root.cellSize,
+                                     Math.floor(i / player.grid_width) * root.cellSize,
+                                     root.cellSize, root.cellSize)
+                    }
+                }
+            }
+        }
+    }
+}
@@ -6,22 +6,78 @@ import QtQuick.Layouts
 import AOCSolutions
 
 ApplicationWindow {
+    id: window
     visible: true
     minimumWidth: 400
     minimumHeight: 250
     width: 800
     height: 600
 
+    readonly property bool darkTheme: solutions.theme == "dark"
+    palette.window: darkTheme ? "#2b2b2b" : "#efefef"
+    palette.windowText: darkTheme ? "#e0e0e0" : "#000000"
+    palette.base: darkTheme ? "#1e1e1e" : "#ffffff"
+    palette.alternateBase: darkTheme ? "#262626" : "#f5f5f5"
+    palette.text: darkTheme ? "#e0e0e0" : "#000000"
+    palette.button: darkTheme ? "#3c3c3c" : "#e0e0e0"
+    palette.buttonText: darkTheme ? "#e0e0e0" : "#000000"
+    palette.placeholderText: darkTheme ? "#808080" : "#a0a0a0"
+    palette.highlight: "#1565c0"
+    palette.highlightedText: "#ffffff"
+
+    onClosing: {
+        solutions.window_x = window.x
+        solutions.window_y = window.y
+        solutions.window_width = window.width
+        solutions.window_height = window.height
+        solutions.save_settings()
+    }
+
+    function formatTime(ms) {
+        return ms < 1 ? (ms * 1000).toFixed(0) + " \u00b5s" : ms.toFixed(3) + " ms"
+    }
+
+    function formatBytes(bytes) {
+        if (bytes < 1024) {
+            return bytes + " B"
+        }
+        if (bytes < 1024 * 1024) {
+            return (bytes / 1024).toFixed(1) + " KiB"
+        }
+        return (bytes / (1024 * 1024)).toFixed(1) + " MiB"
+    }
+
     AOCSolutions {
         id: solutions
-        inputs_dir: inputsPath.text
+
+        Component.onCompleted: {
+            solutions.load_settings()
+            inputsPath.text = solutions.inputs_dir
+            if (solutions.window_width > 0 && solutions.window_height > 0) {
+                window.x = solutions.window_x
+                window.y = solutions.window_y
+                window.width = solutions.window_width
+                window.height = solutions.window_height
+            }
+            solutions.refresh_days()
+        }
     }
     
+    Timer {
+        interval: 500
+        repeat: true
+        running: solutions.watching
+        onTriggered: {
+            solutions.poll_changes()
+        }
+    }
+
     FolderDialog {
         id: folderPicker
         
         onAccepted: {
-            inputsPath.text = folderPicker.currentFolder.toString().slice(7)
+            solutions.set_inputs_dir_url(folderPicker.selectedFolder)
+            inputsPath.text = solutions.inputs_dir
         }
     }
 
@@ -37,6 +93,10 @@ ApplicationWindow {
             TextField {
                 id: inputsPath
                 Layout.fillWidth: true
+
+                onEditingFinished: {
+                    solutions.inputs_dir = inputsPath.text
+                }
             }
 
             Button {
@@ -45,82 +105,303 @@ ApplicationWindow {
                     folderPicker.visible = true
                 }
             }
-        }
 
-        GridLayout {
-            Layout.alignment: Qt.AlignHCenter
-            Layout.margins: 20
-            columns: 5
-            rows:5
-            
-            Button {
-                text: "Day 1"
-                onClicked: {
-                    solutions.request_solution(1)
+            ComboBox {
+                Layout.preferredWidth: 140
+                model: ["(default)"].concat(solutions.profiles)
+                currentIndex: solutions.profiles.indexOf(solutions.profile) + 1
+                visible: solutions.profiles.length > 0
+                onActivated: (index) => {
+                    solutions.profile = index > 0 ? currentText : ""
                 }
             }
-            
-            Button {
-                text: "Day 2"
-                onClicked: {
-                    solutions.request_solution(2)
+
+            CheckBox {
+                text: "Watch inputs"
+                checked: solutions.watching
+                onToggled: {
+                    solutions.watching = checked
                 }
             }
-            
-            Button {
-                text: "Day 3"
-                onClicked: {
-                    solutions.request_solution(3)
+
+            ComboBox {
+                model: ["light", "dark"]
+                currentIndex: window.darkTheme ? 1 : 0
+                onActivated: {
+                    solutions.theme = currentText
                 }
             }
+        }
 
-            Button {
-                text: "Day 4"
-                onClicked: {
-                    solutions.request_solution(4)
+        ColumnLayout {
+            Layout.leftMargin: 20
+            Layout.rightMargin: 20
+            Layout.bottomMargin: 10
+            visible: solutions.input_warnings.length > 0
+
+            Repeater {
+                model: solutions.input_warnings
+
+                Label {
+                    text: "\u26a0 " + modelData
+                    color: "#e65100"
                 }
             }
         }
 
-        GridLayout {
-            Layout.alignment: Qt.AlignHCenter
-            Layout.margins: 20
-            columns: 2
-            rows:3
+        TabBar {
+            id: tabs
+            Layout.fillWidth: true
+
+            TabButton {
+                text: "Solutions"
+            }
+
+            TabButton {
+                text: "Input editor"
+            }
+
+            TabButton {
+                text: "Day 1 location lists"
+            }
+
+            TabButton {
+                text: "Day 2 reports"
+            }
 
-            Label {
-                text: "One star solution"
-                visible: solutions.error_msg.length == 0
+            TabButton {
+                text: "Day 3 corrupted memory"
             }
-            TextArea {
+
+            TabButton {
+                text: "Day 4 word search"
+            }
+
+            TabButton {
+                text: "Simulation player"
+            }
+        }
+
+        StackLayout {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            currentIndex: tabs.currentIndex
+
+            ColumnLayout {
+                spacing: 0
+
+                GridLayout {
+                    Layout.alignment: Qt.AlignHCenter
+                    Layout.margins: 20
+                    columns: 5
+                    rows:5
+
+                    Repeater {
+                        model: solutions.days
+
+                        Button {
+                            text: "Day " + number + (verified ? " \u2713" : "")
+                            enabled: implemented && input_present
+                            highlighted: cached
+                            ToolTip.visible: hovered
+                            ToolTip.text: title + (input_present
+                                ? "\n" + formatBytes(input_size) + ", modified "
+                                    + (input_modified > 0 ? new Date(input_modified).toLocaleString() : "unknown")
+                                : "\nno input file")
+                            onClicked: {
+                                solutions.request_solution(number)
+                            }
+                        }
+                    }
+                }
+
+                GridLayout {
+                    Layout.alignment: Qt.AlignHCenter
+                    Layout.margins: 20
+                    columns: 4
+                    rows:5
+
+                    Label {
+                        text: "One star solution"
+                        visible: solutions.error_msg.length == 0
+                    }
+                    TextArea {
                     
-                readOnly: true
-                horizontalAlignment: TextEdit.AlignHCenter
-                text: solutions.solution1
-                visible: solutions.error_msg.length == 0
+                        readOnly: true
+                        horizontalAlignment: TextEdit.AlignHCenter
+                        text: solutions.solution1
+                        visible: solutions.error_msg.length == 0
+                    }
+                    Label {
+                        text: formatTime(solutions.one_star_time_ms)
+                        visible: solutions.error_msg.length == 0 && solutions.solution1.length > 0
+                    }
+                    Button {
+                        text: "Submit"
+                        visible: solutions.error_msg.length == 0 && solutions.solution1.length > 0
+                            && solutions.submittable
+                        onClicked: solutions.submit_answer(1)
+                    }
+
+                    Label {
+                        text: "Two star solution"
+                        visible: solutions.error_msg.length == 0 && !solutions.single_star
+                    }
+                    TextArea {
+                        readOnly: true
+                        horizontalAlignment: TextEdit.AlignHCenter
+                        text: solutions.solution2
+                        visible: solutions.error_msg.length == 0 && !solutions.single_star
+                    }
+                    Label {
+                        text: formatTime(solutions.two_star_time_ms)
+                        visible: solutions.error_msg.length == 0 && !solutions.single_star
+                            && solutions.solution2.length > 0
+                    }
+                    Button {
+                        text: "Submit"
+                        visible: solutions.error_msg.length == 0 && !solutions.single_star
+                            && solutions.solution2.length > 0 && solutions.submittable
+                        onClicked: solutions.submit_answer(2)
+                    }
+
+                    Label {
+                        text: "Parsing " + formatTime(solutions.parse_time_ms)
+                            + ", peak memory " + formatBytes(solutions.peak_bytes)
+                        visible: solutions.error_msg.length == 0 && solutions.solution1.length > 0
+                        Layout.columnSpan: 4
+                    }
+
+                    Label {
+                        text: solutions.submission_status
+                        visible: solutions.submission_status.length > 0
+                        Layout.columnSpan: 4
+                    }
+
+                    Label {
+                        text: solutions.error_msg
+                        visible: solutions.error_msg.length > 0
+                        Layout.fillHeight: false
+                    }
+
+                    Item {
+                        // filler
+                        Layout.fillHeight: true
+                        Layout.rowSpan: 2
+                    }
+                }
             }
 
-            Label {
-                text: "Two star solution"
-                visible: solutions.error_msg.length == 0
+            ColumnLayout {
+                id: editor
+                property int dayNumber: Math.max(solutions.last_day, 1)
+
+                function reloadVariants() {
+                    variantPicker.model = ["input file"].concat(solutions.variant_names(editor.dayNumber))
+                }
+
+                RowLayout {
+                    Layout.margins: 20
+                    Layout.bottomMargin: 0
+
+                    Label {
+                        text: "Day"
+                    }
+                    SpinBox {
+                        from: 1
+                        to: 25
+                        value: editor.dayNumber
+                        onValueModified: {
+                            editor.dayNumber = value
+                            editor.reloadVariants()
+                        }
+                    }
+
+                    ComboBox {
+                        id: variantPicker
+                        Layout.preferredWidth: 200
+                        model: ["input file"]
+                    }
+
+                    Button {
+                        text: "Load"
+                        onClicked: {
+                            var variant = variantPicker.currentIndex > 0 ? variantPicker.currentText : ""
+                            editorText.text = solutions.load_text(editor.dayNumber, variant)
+                        }
+                    }
+
+                    Button {
+                        text: "Solve"
+                        onClicked: {
+                            solutions.solve_text(editor.dayNumber, editorText.text)
+                        }
+                    }
+
+                    TextField {
+                        id: variantName
+                        placeholderText: "variant name"
+                    }
+
+                    Button {
+                        text: "Save"
+                        enabled: variantName.text.length > 0
+                        onClicked: {
+                            if (solutions.save_text(editor.dayNumber, variantName.text, editorText.text)) {
+                                editor.reloadVariants()
+                            }
+                        }
+                    }
+                }
+
+                Label {
+                    Layout.leftMargin: 20
+                    text: solutions.error_msg.length > 0 ? solutions.error_msg
+                        : solutions.solution1.length == 0 ? ""
+                        : "One star: " + solutions.solution1
+                            + (solutions.single_star ? "" : ", two star: " + solutions.solution2)
+                            + " (" + formatTime(solutions.parse_time_ms + solutions.one_star_time_ms
+                                                + solutions.two_star_time_ms) + ")"
+                }
+
+                ScrollView {
+                    Layout.fillWidth: true
+                    Layout.fillHeight: true
+                    Layout.margins: 20
+
+                    TextArea {
+                        id: editorText
+                        font.family: "monospace"
+                        placeholderText: "Paste an input or load one of the files"
+                    }
+                }
+
+                Connections {
+                    target: solutions
+                    function onInputs_dir_changed() {
+                        editor.reloadVariants()
+                    }
+                }
             }
-            TextArea {
-                readOnly: true
-                horizontalAlignment: TextEdit.AlignHCenter
-                text: solutions.solution2
-                visible: solutions.error_msg.length == 0
+
+            Day1View {
+                inputsDir: solutions.active_inputs_dir
+            }
+
+            Day2View {
+                inputsDir: solutions.active_inputs_dir
+            }
+
+            Day3View {
+                inputsDir: solutions.active_inputs_dir
             }
 
-            Label {
-                text: solutions.error_msg
-                visible: solutions.error_msg.length > 0
-                Layout.fillHeight: false
+            Day4View {
+                inputsDir: solutions.active_inputs_dir
             }
 
-            Item {
-                // filler
-                Layout.fillHeight: true
-                Layout.rowSpan: 2
+            FramePlayerView {
+                inputsDir: solutions.active_inputs_dir
             }
         }
     }
@@ -0,0 +1,397 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::settings;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: i32 = 2024;
+
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+const USER_AGENT: &str = "github.com/wartum/AOC24";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Download {
+    Saved(PathBuf),
+    AlreadyPresent(PathBuf),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    Wait(u64),
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::Wait(seconds) => write!(f, "wait {seconds}s"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "correct" => Ok(Verdict::Correct),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "already solved" => Ok(Verdict::AlreadySolved),
+            text => text
+                .strip_prefix("wait ")
+                .and_then(|seconds| seconds.strip_suffix('s'))
+                .and_then(|seconds| seconds.parse().ok())
+                .map(Verdict::Wait)
+                .ok_or(format!("invalid verdict \"{text}\"")),
+        }
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            min_interval: MIN_REQUEST_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(session) if !session.trim().is_empty() => session,
+            _ => {
+                let path = settings::config_dir()
+                    .ok_or("cannot determine config directory".to_string())?
+                    .join("session");
+                std::fs::read_to_string(&path).map_err(|err| {
+                    format!(
+                        "no session cookie, set AOC_SESSION or write it to \"{}\": {err}",
+                        path.display()
+                    )
+                })?
+            }
+        };
+        if session.trim().is_empty() {
+            return Err(String::from("session cookie is empty"));
+        }
+
+        let base_url = match std::env::var("AOC_BASE_URL") {
+            Ok(base_url) if !base_url.is_empty() => base_url,
+            _ => String::from(DEFAULT_BASE_URL),
+        };
+        Ok(Self::new(&base_url, &session))
+    }
+
+    #[cfg(test)]
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn download_input(
+        &mut self,
+        day_number: i32,
+        inputs_dir: &str,
+    ) -> Result<Download, String> {
+        if !(1..=25).contains(&day_number) {
+            return Err(format!("invalid day number {day_number}"));
+        }
+        let path = Path::new(inputs_dir).join(format!("day{day_number}.txt"));
+        if path.exists() {
+            return Ok(Download::AlreadyPresent(path));
+        }
+
+        let input = self.get(&format!("/{YEAR}/day/{day_number}/input"))?;
+        if input.is_empty() {
+            return Err(format!(
+                "server returned an empty input for day {day_number}"
+            ));
+        }
+
+        std::fs::create_dir_all(inputs_dir)
+            .and_then(|_| std::fs::write(&path, input))
+            .map_err(|err| format!("{err}. \"{}\"", path.display()))?;
+        Ok(Download::Saved(path))
+    }
+
+    pub fn submit_answer(
+        &mut self,
+        day_number: i32,
+        part: i32,
+        answer: &str,
+    ) -> Result<Verdict, String> {
+        self.throttle();
+        let url = format!("{}/{YEAR}/day/{day_number}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        parse_verdict(&read_response(response, &url)?)
+    }
+
+    fn get(&mut self, path: &str) -> Result<String, String> {
+        self.throttle();
+        let url = format!("{}{path}", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(response, &url)
+    }
+
+    fn throttle(&mut self) {
+        if let Some(elapsed) = self.last_request.map(|instant| instant.elapsed()) {
+            if elapsed < self.min_interval {
+                std::thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+fn read_response(
+    response: Result<ureq::Response, ureq::Error>,
+    url: &str,
+) -> Result<String, String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| format!("cannot read response from {url}: {err}")),
+        Err(ureq::Error::Status(status @ (400 | 401 | 403), _)) => Err(format!(
+            "{url} returned {status}, the session cookie is probably invalid or expired"
+        )),
+        Err(ureq::Error::Status(404, _)) => Err(format!(
+            "{url} returned 404, the puzzle is probably not unlocked yet"
+        )),
+        Err(ureq::Error::Status(status, _)) => Err(format!("{url} returned {status}")),
+        Err(ureq::Error::Transport(err)) => Err(format!("cannot reach {url}: {err}")),
+    }
+}
+
+fn parse_verdict(html: &str) -> Result<Verdict, String> {
+    if html.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if html.contains("You gave an answer too recently") {
+        Ok(Verdict::Wait(parse_wait(html).unwrap_or(60)))
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else {
+            Ok(Verdict::Incorrect)
+        }
+    } else if html.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::AlreadySolved)
+    } else {
+        Err(String::from(
+            "unrecognized response to the submitted answer",
+        ))
+    }
+}
+
+fn parse_wait(html: &str) -> Option<u64> {
+    let text = &html[..html.find(" left to wait")?];
+    let text = &text[text.rfind("have ")? + "have ".len()..];
+
+    text.split_whitespace()
+        .map(|amount| {
+            let unit = amount.chars().last()?;
+            let number: u64 = amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+            match unit {
+                'h' => Some(number * 3600),
+                'm' => Some(number * 60),
+                's' => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::stand_in::StandIn;
+
+    use super::{parse_verdict, Client, Download, Verdict};
+
+    fn temp_dir(name: &str) -> String {
+        let dir = std::env::temp_dir().join(format!("aoc24-client-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    pub fn download_input() {
+        let server = StandIn::start(|request| match request.path.as_str() {
+            "/2024/day/1/input" => (200, String::from("3   4\n4   3\n")),
+            _ => (404, String::from("Not Found")),
+        });
+        let dir = temp_dir("download");
+        let mut client = Client::new(server.url(), "abc123").with_min_interval(Duration::ZERO);
+
+        let download = client
+            .download_input(1, &dir)
+            .expect("expected Ok(Download), got Err()");
+        let path = std::path::Path::new(&dir).join("day1.txt");
+        assert_eq!(download, Download::Saved(path.clone()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+
+        assert!(client.download_input(2, &dir).is_err());
+        assert!(!std::path::Path::new(&dir).join("day2.txt").exists());
+        assert!(client.download_input(26, &dir).is_err());
+        assert_eq!(server.requests().len(), 2);
+        std::fs::remove_dir_all(&dir).expect("cannot clean up");
+    }
+
+    #[test]
+    pub fn never_downloads_twice() {
+        let server = StandIn::start(|_| (200, String::from("new input\n")));
+        let dir = temp_dir("existing");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = std::path::Path::new(&dir).join("day3.txt");
+        std::fs::write(&path, "old input\n").unwrap();
+        let mut client = Client::new(server.url(), "abc123");
+
+        let download = client
+            .download_input(3, &dir)
+            .expect("expected Ok(Download), got Err()");
+        assert_eq!(download, Download::AlreadyPresent(path.clone()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "old input\n");
+        assert!(server.requests().is_empty());
+        std::fs::remove_dir_all(&dir).expect("cannot clean up");
+    }
+
+    #[test]
+    pub fn rate_limit() {
+        let server = StandIn::start(|_| (200, String::from("input\n")));
+        let dir = temp_dir("rate-limit");
+        let interval = Duration::from_millis(200);
+        let mut client = Client::new(server.url(), "abc123").with_min_interval(interval);
+
+        let start = Instant::now();
+        for day_number in 1..=3 {
+            client
+                .download_input(day_number, &dir)
+                .expect("expected Ok(Download), got Err()");
+        }
+        assert!(start.elapsed() >= interval * 2);
+        assert_eq!(server.requests().len(), 3);
+        std::fs::remove_dir_all(&dir).expect("cannot clean up");
+    }
+
+    #[test]
+    pub fn rejected_session() {
+        let server = StandIn::start(|_| (400, String::from("Please log in.")));
+        let mut client = Client::new(server.url(), "expired");
+
+        let err = client
+            .download_input(1, &temp_dir("rejected"))
+            .expect_err("expected Err(), got Ok(Download)");
+        assert!(err.contains("session cookie"));
+    }
+
+    #[test]
+    pub fn verdicts() {
+        let page = |text: &str| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high. Please wait one minute.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low. Please wait one minute.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer. Please wait one minute.",
+                Verdict::Incorrect,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 25s left to wait.",
+                Verdict::Wait(85),
+            ),
+            (
+                "You gave an answer too recently. You have 37s left to wait.",
+                Verdict::Wait(37),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+        ];
+        for (text, verdict) in cases {
+            assert_eq!(parse_verdict(&page(text)), Ok(verdict));
+            assert_eq!(verdict.to_string().parse(), Ok(verdict));
+        }
+        assert!(parse_verdict(&page("Please log in.")).is_err());
+    }
+
+    #[test]
+    pub fn submit_answer() {
+        let server = StandIn::start(|request| match request.path.as_str() {
+            "/2024/day/1/answer" if request.body == "level=2&answer=31" => (
+                200,
+                String::from("<article><p>That's the right answer!</p></article>"),
+            ),
+            _ => (
+                200,
+                String::from("<article><p>That's not the right answer.</p></article>"),
+            ),
+        });
+        let mut client = Client::new(server.url(), "abc123").with_min_interval(Duration::ZERO);
+
+        let verdict = client
+            .submit_answer(1, 2, "31")
+            .expect("expected Ok(Verdict), got Err()");
+        assert_eq!(verdict, Verdict::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+        assert_eq!(
+            requests[0].header("Content-Type"),
+            Some("application/x-www-form-urlencoded")
+        );
+    }
+}
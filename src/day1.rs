@@ -1,17 +1,56 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-use crate::utils::Solution;
+use crate::utils::{Answer, Puzzle};
 
-pub fn solve(input: String) -> Result<Solution, String> {
-    match parse_input(input) {
-        Err(msg) => Err(msg),
-        Ok(columns) => Ok(Solution {
-            one_star_answer: calculate_distances(&columns),
-            two_star_answer: calculate_similiarities(&columns),
-        }),
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    pub left: i32,
+    pub right: i32,
+    pub distance: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Occurrence {
+    pub value: i32,
+    pub left_count: i32,
+    pub right_count: i32,
+    pub similarity: i32,
+}
+
+pub struct Breakdown {
+    pub columns: [Vec<i32>; 2],
+    pub pairs: Vec<Pair>,
+    pub occurrences: Vec<Occurrence>,
+}
+
+struct LocationLists([Vec<i32>; 2]);
+
+impl Puzzle for LocationLists {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        Ok(calculate_distances(&self.0).into())
+    }
+
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        Ok(calculate_similiarities(&self.0).into())
     }
 }
 
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(LocationLists(parse_input(input)?)))
+}
+
+pub fn breakdown(input: String) -> Result<Breakdown, String> {
+    let columns = parse_input(input)?;
+    let pairs = pair_up(&columns);
+    let occurrences = count_occurrences(&columns);
+
+    Ok(Breakdown {
+        columns,
+        pairs,
+        occurrences,
+    })
+}
+
 fn parse_input(input: String) -> Result<[Vec<i32>; 2], String> {
     let column1: Result<Vec<i32>, _> = input
         .lines()
@@ -46,40 +85,54 @@ fn parse_input(input: String) -> Result<[Vec<i32>; 2], String> {
     Err("Could not parse input".into())
 }
 
-fn calculate_distances(columns: &[Vec<i32>; 2]) -> i32 {
-    let mut distances = 0;
-    let mut column1 = columns[0].iter();
-    let mut column2 = columns[1].iter();
-
-    for _ in 0..column1.len() {
-        if let Some(val1) = column1.next() {
-            if let Some(val2) = column2.next() {
-                distances += (val1 - val2).abs();
-            }
-        }
-    }
-
-    distances
+fn pair_up(columns: &[Vec<i32>; 2]) -> Vec<Pair> {
+    columns[0]
+        .iter()
+        .zip(columns[1].iter())
+        .map(|(&left, &right)| Pair {
+            left,
+            right,
+            distance: (left - right).abs(),
+        })
+        .collect()
 }
 
-fn calculate_similiarities(columns: &[Vec<i32>; 2]) -> i32 {
-    let mut dict = HashMap::new();
+fn count_occurrences(columns: &[Vec<i32>; 2]) -> Vec<Occurrence> {
+    let mut counts: BTreeMap<i32, (i32, i32)> = BTreeMap::new();
     for v in columns[0].iter() {
-        let occurences_in_column2: i32 = columns[1].iter().filter(|x| **x == *v).count() as i32;
-        dict.insert(*v, v * occurences_in_column2);
+        counts.entry(*v).or_default().0 += 1;
     }
+    for v in columns[1].iter() {
+        counts.entry(*v).or_default().1 += 1;
+    }
+
+    counts
+        .into_iter()
+        .map(|(value, (left_count, right_count))| Occurrence {
+            value,
+            left_count,
+            right_count,
+            similarity: value * left_count * right_count,
+        })
+        .collect()
+}
+
+fn calculate_distances(columns: &[Vec<i32>; 2]) -> i32 {
+    pair_up(columns).iter().map(|pair| pair.distance).sum()
+}
 
-    columns[0].iter().map(|v| dict.get(v).unwrap_or(&0)).sum()
+fn calculate_similiarities(columns: &[Vec<i32>; 2]) -> i32 {
+    count_occurrences(columns)
+        .iter()
+        .map(|occurrence| occurrence.similarity)
+        .sum()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day1;
-    use crate::utils;
 
-    fn real_input() -> String {
-        utils::get_input(1, "resources/inputs").expect("Cannot get input")
-    }
+    use super::{Occurrence, Pair};
 
     fn sample_input() -> String {
         String::from(
@@ -93,26 +146,36 @@ mod tests {
     }
 
     #[test]
-    pub fn sample_input_one_star_answer() {
-        let solution = day1::solve(sample_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.one_star_answer, 11);
-    }
-
-    #[test]
-    pub fn sample_input_two_star_answer() {
-        let solution = day1::solve(sample_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.two_star_answer, 31);
-    }
-
-    #[test]
-    pub fn one_star_answer() {
-        let solution = day1::solve(real_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.one_star_answer, 3714264);
-    }
-
-    #[test]
-    pub fn two_star_answer() {
-        let solution = day1::solve(real_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.two_star_answer, 18805872);
+    pub fn breakdown() {
+        let breakdown = day1::breakdown(sample_input()).expect("expected Ok(Breakdown), got Err()");
+
+        let distances: Vec<i32> = breakdown.pairs.iter().map(|pair| pair.distance).collect();
+        assert_eq!(distances, vec![2, 1, 0, 1, 2, 5]);
+        assert_eq!(
+            breakdown.pairs[5],
+            Pair {
+                left: 4,
+                right: 9,
+                distance: 5,
+            }
+        );
+
+        assert_eq!(
+            breakdown.occurrences[2],
+            Occurrence {
+                value: 3,
+                left_count: 3,
+                right_count: 3,
+                similarity: 27,
+            }
+        );
+        assert_eq!(
+            breakdown
+                .occurrences
+                .iter()
+                .map(|occurrence| occurrence.value)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 9]
+        );
     }
 }
@@ -1,33 +1,124 @@
-use crate::utils::Solution;
-
-pub fn solve(input: String) -> Result<Solution, String> {
-    match parse_input(input) {
-        Err(msg) => Err(msg),
-        Ok(reports) => {
-            let mut one_star_answer = 0;
-            let mut two_star_answer = 0;
-
-            for report in reports.iter() {
-                if is_report_safe(report) {
-                    one_star_answer += 1;
-                    two_star_answer += 1;
-                } else {
-                    for i in 0..report.len() {
-                        let new_report = remove_from_report(report, i as i32);
-                        if is_report_safe(&new_report) {
-                            two_star_answer += 1;
-                            break;
-                        }
-                    }
-                }
-            }
+use std::fmt;
+
+use crate::utils::{Answer, Puzzle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    TooShort,
+    ZeroGap(usize),
+    DirectionChange(usize),
+    GapTooLarge(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagnostic {
+    Safe,
+    Unsafe(Violation),
+    SavedByRemoving { violation: Violation, index: usize },
+}
 
-            Ok(Solution {
-                one_star_answer,
-                two_star_answer,
-            })
+impl Diagnostic {
+    pub const CATEGORIES: [&'static str; 6] = [
+        "safe",
+        "saved by removing",
+        "zero gap",
+        "direction change",
+        "gap too large",
+        "too short",
+    ];
+
+    pub fn category(&self) -> &'static str {
+        match self {
+            Diagnostic::Safe => "safe",
+            Diagnostic::SavedByRemoving { .. } => "saved by removing",
+            Diagnostic::Unsafe(Violation::ZeroGap(_)) => "zero gap",
+            Diagnostic::Unsafe(Violation::DirectionChange(_)) => "direction change",
+            Diagnostic::Unsafe(Violation::GapTooLarge(_)) => "gap too large",
+            Diagnostic::Unsafe(Violation::TooShort) => "too short",
         }
     }
+
+    pub fn level_index(&self) -> Option<usize> {
+        match self {
+            Diagnostic::Safe | Diagnostic::Unsafe(Violation::TooShort) => None,
+            Diagnostic::Unsafe(Violation::ZeroGap(index))
+            | Diagnostic::Unsafe(Violation::DirectionChange(index))
+            | Diagnostic::Unsafe(Violation::GapTooLarge(index))
+            | Diagnostic::SavedByRemoving { index, .. } => Some(*index),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Violation::TooShort => write!(f, "fewer than 2 levels"),
+            Violation::ZeroGap(index) => write!(f, "no change at {index}"),
+            Violation::DirectionChange(index) => write!(f, "direction change at {index}"),
+            Violation::GapTooLarge(index) => write!(f, "gap larger than 3 at {index}"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Diagnostic::Safe => write!(f, "safe"),
+            Diagnostic::Unsafe(violation) => write!(f, "unsafe, {violation}"),
+            Diagnostic::SavedByRemoving { violation, index } => {
+                write!(f, "safe after removing {index} (was {violation})")
+            }
+        }
+    }
+}
+
+struct Reports(Vec<Vec<i32>>);
+
+impl Puzzle for Reports {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        let safe_reports = self
+            .0
+            .iter()
+            .filter(|report| check_report(report).is_ok())
+            .count();
+        Ok((safe_reports as i32).into())
+    }
+
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        let safe_reports = self
+            .0
+            .iter()
+            .filter(|report| !matches!(diagnose(report), Diagnostic::Unsafe(_)))
+            .count();
+        Ok((safe_reports as i32).into())
+    }
+}
+
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(Reports(parse_input(input)?)))
+}
+
+pub fn diagnose_reports(input: String) -> Result<Vec<(Vec<i32>, Diagnostic)>, String> {
+    Ok(parse_input(input)?
+        .into_iter()
+        .map(|report| {
+            let diagnostic = diagnose(&report);
+            (report, diagnostic)
+        })
+        .collect())
+}
+
+pub fn diagnose(report: &[i32]) -> Diagnostic {
+    let violation = match check_report(report) {
+        Ok(()) => return Diagnostic::Safe,
+        Err(violation) => violation,
+    };
+
+    (0..report.len())
+        .find(|&i| check_report(&remove_from_report(report, i as i32)).is_ok())
+        .map_or(Diagnostic::Unsafe(violation), |index| {
+            Diagnostic::SavedByRemoving { violation, index }
+        })
 }
 
 fn parse_input(input: String) -> Result<Vec<Vec<i32>>, String> {
@@ -58,45 +149,38 @@ fn remove_from_report(report: &[i32], index: i32) -> Vec<i32> {
     new_report
 }
 
-fn is_report_safe(report: &[i32]) -> bool {
+fn check_report(report: &[i32]) -> Result<(), Violation> {
     if report.len() < 2 {
-        eprintln!("report should contain at least 2 elements");
-        return false;
+        return Err(Violation::TooShort);
     }
-    let first_element = *report
-        .first()
-        .expect("report should contain at least 2 elements");
-    let second_element = *report
-        .get(1)
-        .expect("report should contain at least 2 elements");
+    let first_element = report[0];
+    let second_element = report[1];
 
     let initial_ordering = first_element.cmp(&second_element);
     let mut previous_level = &first_element;
 
-    for current_level in report.iter().skip(1) {
+    for (i, current_level) in report.iter().enumerate().skip(1) {
+        if current_level == previous_level {
+            return Err(Violation::ZeroGap(i));
+        }
         let current_ordering = previous_level.cmp(current_level);
         if current_ordering != initial_ordering {
-            return false;
+            return Err(Violation::DirectionChange(i));
         }
         if (current_level - previous_level).abs() > 3 {
-            return false;
+            return Err(Violation::GapTooLarge(i));
         }
         previous_level = current_level;
     }
     
-    true
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils;
     use crate::day2;
 
-    use super::remove_from_report;
-
-    fn real_input() -> String {
-        utils::get_input(2, "resources/inputs").expect("Cannot get input")
-    }
+    use super::{diagnose, remove_from_report, Diagnostic, Violation};
 
     fn sample_input() -> String {
         String::from(
@@ -122,26 +206,31 @@ mod tests {
     }
 
     #[test]
-    pub fn sample_input_one_star_answer() {
-        let solution = day2::solve(sample_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.one_star_answer, 2);
-    }
-
-    #[test]
-    pub fn one_star_answer() {
-        let solution = day2::solve(real_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.one_star_answer, 220);
-    }
-
-    #[test]
-    pub fn sample_input_two_star_answer() {
-        let solution = day2::solve(sample_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.two_star_answer, 4);
-    }
-
-    #[test]
-    pub fn two_star_answer() {
-        let solution = day2::solve(real_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.two_star_answer, 296);
+    pub fn diagnostics() {
+        let diagnostics: Vec<Diagnostic> = day2::diagnose_reports(sample_input())
+            .expect("expected Ok(diagnostics), got Err()")
+            .into_iter()
+            .map(|(_, diagnostic)| diagnostic)
+            .collect();
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::Safe,
+                Diagnostic::Unsafe(Violation::GapTooLarge(2)),
+                Diagnostic::Unsafe(Violation::GapTooLarge(3)),
+                Diagnostic::SavedByRemoving {
+                    violation: Violation::DirectionChange(2),
+                    index: 1,
+                },
+                Diagnostic::SavedByRemoving {
+                    violation: Violation::ZeroGap(3),
+                    index: 2,
+                },
+                Diagnostic::Safe,
+            ]
+        );
+        assert_eq!(diagnose(&[1]), Diagnostic::Unsafe(Violation::TooShort));
+        assert_eq!(diagnose(&[5, 5, 5]), Diagnostic::Unsafe(Violation::ZeroGap(1)));
     }
 }
@@ -0,0 +1,198 @@
+use crate::frames::Recording;
+use crate::utils::{Answer, Puzzle};
+
+const MAX_RECORDED_FRAMES: usize = 200;
+
+#[derive(Debug)]
+struct Racetrack {
+    path: Vec<(i32, i32)>,
+}
+
+impl Racetrack {
+    pub fn from(input: &str) -> Result<Self, String> {
+        if !input.chars().all(Self::is_valid_char) {
+            return Err("Input contains invalid characters".to_string());
+        }
+
+        let grid: Vec<&[u8]> = input.lines().map(|line| line.trim().as_bytes()).collect();
+        let height = grid.len();
+        let width = grid.first().ok_or("empty input".to_string())?.len();
+        if grid.iter().any(|row| row.len() != width) {
+            return Err("Racetrack rows have different lengths".to_string());
+        }
+
+        let find = |tile: u8| {
+            grid.iter().enumerate().find_map(|(y, row)| {
+                row.iter()
+                    .position(|b| *b == tile)
+                    .map(|x| (x as i32, y as i32))
+            })
+        };
+        let start = find(b'S').ok_or("racetrack has no start".to_string())?;
+        let end = find(b'E').ok_or("racetrack has no end".to_string())?;
+
+        let is_track = |(x, y): (i32, i32)| {
+            x >= 0
+                && y >= 0
+                && (x as usize) < width
+                && (y as usize) < height
+                && grid[y as usize][x as usize] != b'#'
+        };
+
+        let mut path = vec![start];
+        let mut previous = start;
+        let mut current = start;
+        while current != end {
+            let (x, y) = current;
+            let next = [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)]
+                .into_iter()
+                .find(|position| *position != previous && is_track(*position))
+                .ok_or("racetrack is not a single path from start to end".to_string())?;
+            previous = current;
+            current = next;
+            path.push(current);
+        }
+
+        Ok(Self { path })
+    }
+
+    fn is_valid_char(c: char) -> bool {
+        let allowed_chars = ['#', '.', 'S', 'E', '\r', '\n'];
+        allowed_chars.contains(&c)
+    }
+
+    fn count_cheats(&self, max_cheat: i32, threshold: i32) -> i32 {
+        let mut cheats = 0;
+
+        for (i, (x1, y1)) in self.path.iter().enumerate() {
+            // skipping ahead by less than `threshold` cells can never pay off
+            for (j, (x2, y2)) in self
+                .path
+                .iter()
+                .enumerate()
+                .skip(i + threshold.max(1) as usize)
+            {
+                let cheat_length = (x1 - x2).abs() + (y1 - y2).abs();
+                if cheat_length <= max_cheat && (j - i) as i32 - cheat_length >= threshold {
+                    cheats += 1;
+                }
+            }
+        }
+
+        cheats
+    }
+}
+
+impl Puzzle for Racetrack {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        Ok(self.count_cheats(2, 100).into())
+    }
+
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        Ok(self.count_cheats(20, 100).into())
+    }
+}
+
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(Racetrack::from(&input)?))
+}
+
+pub fn record(input: String) -> Result<Recording, String> {
+    let racetrack = Racetrack::from(&input)?;
+    let mut grid: Vec<Vec<char>> = input
+        .lines()
+        .map(|line| line.trim().chars().collect())
+        .collect();
+    let step = racetrack.path.len().div_ceil(MAX_RECORDED_FRAMES).max(1);
+
+    let mut recording = Recording::new();
+    for (picosecond, (x, y)) in racetrack.path.iter().enumerate() {
+        let cell = &mut grid[*y as usize][*x as usize];
+        let previous = std::mem::replace(cell, '@');
+        if picosecond % step == 0 || picosecond == racetrack.path.len() - 1 {
+            recording.push(
+                format!("{picosecond} ps"),
+                grid.iter().map(|row| row.iter().copied()),
+            )?;
+        }
+        grid[*y as usize][*x as usize] = if previous == '.' { 'o' } else { previous };
+    }
+
+    Ok(recording)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day20;
+    use crate::day20::Racetrack;
+
+    fn sample_input() -> String {
+        r#"###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############"#
+            .to_string()
+    }
+
+    #[test]
+    pub fn record() {
+        let recording = day20::record(sample_input()).expect("expected Ok(Recording), got Err()");
+        let frames = recording.frames();
+
+        assert_eq!(frames.len(), 85);
+        assert_eq!(frames[0].label, "0 ps");
+        assert_eq!(frames[0].cells[3 * 15 + 1], '@');
+        assert_eq!(frames[1].cells[2 * 15 + 1], '@');
+        assert_eq!(frames[1].cells[3 * 15 + 1], 'S');
+
+        let last = frames.last().expect("expected frames");
+        assert_eq!(last.label, "84 ps");
+        assert_eq!(last.cells[7 * 15 + 5], '@');
+        assert_eq!(last.cells.iter().filter(|c| **c == '.').count(), 0);
+    }
+
+    #[test]
+    pub fn parse_input() {
+        let invalid_input = "#S.X.E#".to_string();
+        Racetrack::from(&invalid_input).expect_err("expected Err(), got Ok(Racetrack)");
+
+        let racetrack =
+            Racetrack::from(&sample_input()).expect("expected Ok(Racetrack), got Err()");
+
+        assert_eq!(racetrack.path.len(), 85);
+        assert_eq!(racetrack.path.first(), Some(&(1, 3)));
+        assert_eq!(racetrack.path.last(), Some(&(5, 7)));
+    }
+
+    #[test]
+    pub fn sample_input_short_cheats() {
+        let racetrack =
+            Racetrack::from(&sample_input()).expect("expected Ok(Racetrack), got Err()");
+
+        assert_eq!(racetrack.count_cheats(2, 64), 1);
+        assert_eq!(racetrack.count_cheats(2, 40), 2);
+        assert_eq!(racetrack.count_cheats(2, 20), 5);
+        assert_eq!(racetrack.count_cheats(2, 2), 44);
+    }
+
+    #[test]
+    pub fn sample_input_long_cheats() {
+        let racetrack =
+            Racetrack::from(&sample_input()).expect("expected Ok(Racetrack), got Err()");
+
+        assert_eq!(racetrack.count_cheats(20, 76), 3);
+        assert_eq!(racetrack.count_cheats(20, 74), 7);
+        assert_eq!(racetrack.count_cheats(20, 50), 285);
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use crate::utils::{Answer, Puzzle};
+
+const NUMERIC_KEYPAD: [&str; 4] = ["789", "456", "123", " 0A"];
+const DIRECTIONAL_KEYPAD: [&str; 2] = [" ^A", "<v>"];
+
+#[derive(Debug)]
+struct Keypad {
+    keys: HashMap<char, (i32, i32)>,
+    gap: (i32, i32),
+}
+
+impl Keypad {
+    fn from_layout(layout: &[&str]) -> Self {
+        let mut keys = HashMap::new();
+        let mut gap = (-1, -1);
+
+        for (y, row) in layout.iter().enumerate() {
+            for (x, key) in row.chars().enumerate() {
+                if key == ' ' {
+                    gap = (x as i32, y as i32);
+                } else {
+                    keys.insert(key, (x as i32, y as i32));
+                }
+            }
+        }
+
+        Self { keys, gap }
+    }
+
+    /// Shortest key sequences (ending with `A`) moving an arm from `from` to `to` and pressing it.
+    fn paths(&self, from: char, to: char) -> Vec<String> {
+        let (x1, y1) = self.keys[&from];
+        let (x2, y2) = self.keys[&to];
+        let horizontal = if x2 > x1 { ">" } else { "<" }.repeat((x2 - x1).unsigned_abs() as usize);
+        let vertical = if y2 > y1 { "v" } else { "^" }.repeat((y2 - y1).unsigned_abs() as usize);
+
+        let mut paths = Vec::with_capacity(2);
+        if (x2, y1) != self.gap {
+            paths.push(format!("{horizontal}{vertical}A"));
+        }
+        if (x1, y2) != self.gap {
+            paths.push(format!("{vertical}{horizontal}A"));
+        }
+        paths.dedup();
+
+        paths
+    }
+}
+
+struct KeypadChain {
+    numeric: Keypad,
+    directional: Keypad,
+    cache: HashMap<(char, char, usize), u64>,
+}
+
+impl KeypadChain {
+    fn new() -> Self {
+        Self {
+            numeric: Keypad::from_layout(&NUMERIC_KEYPAD),
+            directional: Keypad::from_layout(&DIRECTIONAL_KEYPAD),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Presses needed to type `code` on the numeric keypad through `robots` directional keypads.
+    fn code_cost(&mut self, code: &str, robots: usize) -> u64 {
+        let mut cost = 0;
+        let mut from = 'A';
+
+        for to in code.chars() {
+            let paths = self.numeric.paths(from, to);
+            cost += paths
+                .iter()
+                .map(|path| self.sequence_cost(path, robots))
+                .min()
+                .unwrap_or_default();
+            from = to;
+        }
+
+        cost
+    }
+
+    fn sequence_cost(&mut self, sequence: &str, depth: usize) -> u64 {
+        let mut cost = 0;
+        let mut from = 'A';
+
+        for to in sequence.chars() {
+            cost += self.move_cost(from, to, depth);
+            from = to;
+        }
+
+        cost
+    }
+
+    fn move_cost(&mut self, from: char, to: char, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        if let Some(cost) = self.cache.get(&(from, to, depth)) {
+            return *cost;
+        }
+
+        let cost = self
+            .directional
+            .paths(from, to)
+            .iter()
+            .map(|path| self.sequence_cost(path, depth - 1))
+            .min()
+            .unwrap_or_default();
+        self.cache.insert((from, to, depth), cost);
+
+        cost
+    }
+}
+
+struct Codes(Vec<String>);
+
+impl Puzzle for Codes {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        complexity_sum(&mut KeypadChain::new(), &self.0, 2).try_into()
+    }
+
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        complexity_sum(&mut KeypadChain::new(), &self.0, 25).try_into()
+    }
+}
+
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(Codes(parse_input(&input)?)))
+}
+
+fn parse_input(input: &str) -> Result<Vec<String>, String> {
+    let numeric = Keypad::from_layout(&NUMERIC_KEYPAD);
+    let codes: Vec<String> = input
+        .lines()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    if codes.is_empty() {
+        return Err("empty input".to_string());
+    }
+    if codes
+        .iter()
+        .any(|code| !code.chars().all(|c| numeric.keys.contains_key(&c)))
+    {
+        return Err("Input contains invalid characters".to_string());
+    }
+
+    Ok(codes)
+}
+
+fn complexity_sum(chain: &mut KeypadChain, codes: &[String], robots: usize) -> u64 {
+    codes
+        .iter()
+        .map(|code| {
+            let numeric_part: u64 = code
+                .chars()
+                .filter(|c| c.is_ascii_digit())
+                .collect::<String>()
+                .parse()
+                .unwrap_or_default();
+            chain.code_cost(code, robots) * numeric_part
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day21;
+
+    use super::{Keypad, KeypadChain, DIRECTIONAL_KEYPAD, NUMERIC_KEYPAD};
+
+    fn sample_input() -> String {
+        r#"029A
+980A
+179A
+456A
+379A"#
+            .to_string()
+    }
+
+    #[test]
+    pub fn parse_input() {
+        day21::parse_input("02BA").expect_err("expected Err(), got Ok(Vec)");
+        let codes = day21::parse_input(&sample_input()).expect("expected Ok(Vec), got Err()");
+        assert_eq!(codes.len(), 5);
+    }
+
+    #[test]
+    pub fn keypad_paths() {
+        let numeric = Keypad::from_layout(&NUMERIC_KEYPAD);
+        let directional = Keypad::from_layout(&DIRECTIONAL_KEYPAD);
+
+        assert_eq!(numeric.paths('A', '0'), vec!["<A"]);
+        assert_eq!(numeric.paths('0', '7'), vec!["^^^<A"]);
+        assert_eq!(numeric.paths('1', 'A'), vec![">>vA"]);
+        assert_eq!(numeric.paths('2', '9'), vec![">^^A", "^^>A"]);
+        assert_eq!(directional.paths('A', '<'), vec!["v<<A"]);
+        assert_eq!(directional.paths('<', '^'), vec![">^A"]);
+        assert_eq!(directional.paths('A', 'A'), vec!["A"]);
+    }
+
+    #[test]
+    pub fn code_cost() {
+        let mut chain = KeypadChain::new();
+
+        assert_eq!(chain.code_cost("029A", 0), 12);
+        assert_eq!(chain.code_cost("029A", 1), 28);
+        assert_eq!(chain.code_cost("029A", 2), 68);
+        assert_eq!(chain.code_cost("980A", 2), 60);
+        assert_eq!(chain.code_cost("179A", 2), 68);
+        assert_eq!(chain.code_cost("456A", 2), 64);
+        assert_eq!(chain.code_cost("379A", 2), 64);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::utils::{Answer, Puzzle};
+
+const PRUNE_MODULO: u64 = 16777216;
+const SECRETS_PER_DAY: usize = 2000;
+/// Price changes are in `-9..=9`, so a sequence of four of them has 19^4 encodings.
+const SEQUENCE_COUNT: usize = 19 * 19 * 19 * 19;
+
+struct Buyers(Vec<u64>);
+
+impl Puzzle for Buyers {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        sum_of_secrets(&self.0, SECRETS_PER_DAY).try_into()
+    }
+
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        most_bananas(&self.0, SECRETS_PER_DAY).try_into()
+    }
+}
+
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(Buyers(parse_input(&input)?)))
+}
+
+fn parse_input(input: &str) -> Result<Vec<u64>, String> {
+    input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.parse::<u64>()
+                .or(Err(format!("cannot convert \"{line}\" to a secret number")))
+        })
+        .collect()
+}
+
+fn next_secret(secret: u64) -> u64 {
+    let secret = ((secret * 64) ^ secret) % PRUNE_MODULO;
+    let secret = ((secret / 32) ^ secret) % PRUNE_MODULO;
+    ((secret * 2048) ^ secret) % PRUNE_MODULO
+}
+
+fn sum_of_secrets(secrets: &[u64], steps: usize) -> u64 {
+    secrets
+        .iter()
+        .map(|secret| (0..steps).fold(*secret, |secret, _| next_secret(secret)))
+        .sum()
+}
+
+fn most_bananas(secrets: &[u64], steps: usize) -> u64 {
+    let mut bananas = vec![0u64; SEQUENCE_COUNT];
+    // index of the last buyer that sold on a sequence, so each buyer only counts its first one
+    let mut last_buyer = vec![usize::MAX; SEQUENCE_COUNT];
+
+    for (buyer, secret) in secrets.iter().enumerate() {
+        let mut secret = *secret;
+        let mut price = (secret % 10) as i64;
+        let mut sequence = 0;
+
+        for step in 0..steps {
+            secret = next_secret(secret);
+            let next_price = (secret % 10) as i64;
+            let change = (next_price - price + 9) as usize;
+            sequence = (sequence * 19 + change) % SEQUENCE_COUNT;
+            price = next_price;
+
+            if step >= 3 && last_buyer[sequence] != buyer {
+                last_buyer[sequence] = buyer;
+                bananas[sequence] += price as u64;
+            }
+        }
+    }
+
+    bananas.into_iter().max().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day22;
+
+    use super::{most_bananas, next_secret, sum_of_secrets};
+
+    fn sample_input() -> String {
+        r#"1
+10
+100
+2024"#
+            .to_string()
+    }
+
+    #[test]
+    pub fn parse_input() {
+        day22::parse_input("12\nabc").expect_err("expected Err(), got Ok(Vec)");
+        let secrets = day22::parse_input(&sample_input()).expect("expected Ok(Vec), got Err()");
+        assert_eq!(secrets, vec![1, 10, 100, 2024]);
+    }
+
+    #[test]
+    pub fn secret_evolution() {
+        let mut secret = 123;
+        let expected = [
+            15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+            5908254,
+        ];
+
+        for value in expected {
+            secret = next_secret(secret);
+            assert_eq!(secret, value);
+        }
+        assert_eq!(sum_of_secrets(&[1], 2000), 8685429);
+    }
+
+    #[test]
+    pub fn single_buyer_bananas() {
+        assert_eq!(most_bananas(&[123], 9), 6);
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::utils::{Answer, Puzzle};
+
+#[derive(Debug)]
+struct Network {
+    names: Vec<String>,
+    connections: Vec<HashSet<usize>>,
+}
+
+impl Network {
+    pub fn from(input: &str) -> Result<Self, String> {
+        let mut indices: HashMap<&str, usize> = HashMap::new();
+        let mut names = Vec::new();
+        let mut connections: Vec<HashSet<usize>> = Vec::new();
+
+        for line in input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+        {
+            let (left, right) = line
+                .split_once('-')
+                .ok_or(format!("connection \"{line}\" is not in \"a-b\" format"))?;
+            if left.is_empty() || right.is_empty() || left == right {
+                return Err(format!("invalid connection \"{line}\""));
+            }
+
+            let mut index_of = |name| {
+                *indices.entry(name).or_insert_with(|| {
+                    names.push(String::from(name));
+                    connections.push(HashSet::new());
+                    names.len() - 1
+                })
+            };
+            let left = index_of(left);
+            let right = index_of(right);
+
+            connections[left].insert(right);
+            connections[right].insert(left);
+        }
+
+        if names.is_empty() {
+            return Err("empty input".to_string());
+        }
+
+        Ok(Self { names, connections })
+    }
+
+    /// Counts sets of three interconnected computers, keeping only those accepted by `filter`.
+    fn count_triangles(&self, filter: impl Fn(&[&str; 3]) -> bool) -> i32 {
+        let mut triangles = 0;
+
+        for (a, neighbours) in self.connections.iter().enumerate() {
+            for b in neighbours.iter().filter(|b| **b > a) {
+                for c in neighbours
+                    .intersection(&self.connections[*b])
+                    .filter(|c| *c > b)
+                {
+                    let names = [&*self.names[a], &*self.names[*b], &*self.names[*c]];
+                    if filter(&names) {
+                        triangles += 1;
+                    }
+                }
+            }
+        }
+
+        triangles
+    }
+
+    fn maximum_clique(&self) -> Vec<usize> {
+        let mut best = Vec::new();
+        let candidates: HashSet<usize> = (0..self.names.len()).collect();
+        self.bron_kerbosch(&mut Vec::new(), candidates, HashSet::new(), &mut best);
+
+        best
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: HashSet<usize>,
+        mut excluded: HashSet<usize>,
+        best: &mut Vec<usize>,
+    ) {
+        if candidates.is_empty() && excluded.is_empty() {
+            if clique.len() > best.len() {
+                *best = clique.clone();
+            }
+            return;
+        }
+
+        // the pivot with most neighbours among candidates leaves the fewest branches to explore
+        let pivot = candidates
+            .union(&excluded)
+            .max_by_key(|v| self.connections[**v].intersection(&candidates).count())
+            .copied()
+            .unwrap_or_default();
+        let branches: Vec<usize> = candidates
+            .difference(&self.connections[pivot])
+            .copied()
+            .collect();
+
+        for v in branches {
+            let neighbours = &self.connections[v];
+            clique.push(v);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(neighbours).copied().collect(),
+                excluded.intersection(neighbours).copied().collect(),
+                best,
+            );
+            clique.pop();
+
+            candidates.remove(&v);
+            excluded.insert(v);
+        }
+    }
+
+    fn password(&self, clique: &[usize]) -> String {
+        let mut names: Vec<&str> = clique.iter().map(|v| &*self.names[*v]).collect();
+        names.sort();
+        names.join(",")
+    }
+}
+
+impl Puzzle for Network {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        let historian_triangles =
+            self.count_triangles(|names| names.iter().any(|name| name.starts_with('t')));
+        Ok(historian_triangles.into())
+    }
+
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        Ok(self.password(&self.maximum_clique()).into())
+    }
+}
+
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(Network::from(&input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day23::Network;
+
+    fn sample_input() -> String {
+        r#"kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn"#
+            .to_string()
+    }
+
+    #[test]
+    pub fn parse_input() {
+        Network::from("kh-tc\nqptc").expect_err("expected Err(), got Ok(Network)");
+        Network::from("kh-kh").expect_err("expected Err(), got Ok(Network)");
+
+        let network = Network::from(&sample_input()).expect("expected Ok(Network), got Err()");
+        assert_eq!(network.names.len(), 16);
+        assert_eq!(
+            network.connections.iter().map(|c| c.len()).sum::<usize>(),
+            64
+        );
+    }
+
+    #[test]
+    pub fn all_triangles() {
+        let network = Network::from(&sample_input()).expect("expected Ok(Network), got Err()");
+        assert_eq!(network.count_triangles(|_| true), 12);
+    }
+}
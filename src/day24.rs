@@ -0,0 +1,341 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::utils::{Answer, Puzzle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    And,
+    Or,
+    Xor,
+}
+
+impl Operation {
+    fn from(name: &str) -> Result<Self, String> {
+        match name {
+            "AND" => Ok(Operation::And),
+            "OR" => Ok(Operation::Or),
+            "XOR" => Ok(Operation::Xor),
+            _ => Err(format!("unknown gate \"{name}\"")),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Operation::And => "AND",
+            Operation::Or => "OR",
+            Operation::Xor => "XOR",
+        }
+    }
+
+    fn apply(&self, a: bool, b: bool) -> bool {
+        match self {
+            Operation::And => a && b,
+            Operation::Or => a || b,
+            Operation::Xor => a ^ b,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Gate {
+    inputs: [String; 2],
+    operation: Operation,
+    output: String,
+}
+
+impl Gate {
+    fn has_input(&self, wire: &str) -> bool {
+        self.inputs.iter().any(|input| input == wire)
+    }
+
+    fn is_fed_by_inputs(&self) -> bool {
+        self.inputs
+            .iter()
+            .all(|input| input.starts_with('x') || input.starts_with('y'))
+    }
+
+    fn is_first_bit(&self) -> bool {
+        self.inputs
+            .iter()
+            .all(|input| input == "x00" || input == "y00")
+    }
+}
+
+#[derive(Debug)]
+struct Circuit {
+    initial_values: Vec<(String, bool)>,
+    gates: Vec<Gate>,
+}
+
+impl Circuit {
+    pub fn from(input: &str) -> Result<Self, String> {
+        let mut initial_values = Vec::new();
+        let mut gates = Vec::new();
+
+        for line in input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+        {
+            if let Some((wire, value)) = line.split_once(": ") {
+                let value = match value {
+                    "0" => false,
+                    "1" => true,
+                    _ => return Err(format!("invalid wire value in \"{line}\"")),
+                };
+                initial_values.push((String::from(wire), value));
+            } else {
+                let words: Vec<&str> = line.split_whitespace().collect();
+                match words[..] {
+                    [a, operation, b, "->", output] => gates.push(Gate {
+                        inputs: [String::from(a), String::from(b)],
+                        operation: Operation::from(operation)?,
+                        output: String::from(output),
+                    }),
+                    _ => return Err(format!("invalid gate \"{line}\"")),
+                }
+            }
+        }
+
+        if gates.is_empty() {
+            return Err("circuit contains no gates".to_string());
+        }
+
+        Ok(Self {
+            initial_values,
+            gates,
+        })
+    }
+
+    fn evaluate(&self) -> Result<HashMap<&str, bool>, String> {
+        let mut values: HashMap<&str, bool> = self
+            .initial_values
+            .iter()
+            .map(|(wire, value)| (wire.as_str(), *value))
+            .collect();
+        let mut pending: Vec<&Gate> = self.gates.iter().collect();
+
+        while !pending.is_empty() {
+            let pending_count = pending.len();
+            pending.retain(|gate| {
+                match (values.get(&*gate.inputs[0]), values.get(&*gate.inputs[1])) {
+                    (Some(a), Some(b)) => {
+                        let value = gate.operation.apply(*a, *b);
+                        values.insert(&gate.output, value);
+                        false
+                    }
+                    _ => true,
+                }
+            });
+
+            if pending.len() == pending_count {
+                return Err("circuit contains unconnected wires or a loop".to_string());
+            }
+        }
+
+        Ok(values)
+    }
+
+    fn output_number(&self) -> Result<u64, String> {
+        let values = self.evaluate()?;
+        let mut z_wires: Vec<(&&str, &bool)> = values
+            .iter()
+            .filter(|(wire, _)| wire.starts_with('z'))
+            .collect();
+        z_wires.sort();
+
+        Ok(z_wires
+            .iter()
+            .rev()
+            .fold(0, |number, (_, bit)| (number << 1) | **bit as u64))
+    }
+
+    /// Gate outputs breaking the structure of a ripple-carry adder, sorted by name.
+    fn swapped_wires(&self) -> Vec<String> {
+        let last_z = self
+            .gates
+            .iter()
+            .map(|gate| &gate.output)
+            .filter(|output| output.starts_with('z'))
+            .max();
+        let feeds = |wire: &str, operation: Operation| {
+            self.gates
+                .iter()
+                .any(|gate| gate.operation == operation && gate.has_input(wire))
+        };
+
+        let mut swapped = BTreeSet::new();
+        for gate in self.gates.iter() {
+            let output = &gate.output;
+            let misplaced = match gate.operation {
+                // every sum bit comes from a XOR, only the final carry comes from an OR
+                _ if output.starts_with('z') && Some(output) != last_z => {
+                    gate.operation != Operation::Xor
+                }
+                Operation::Xor if !gate.is_fed_by_inputs() => !output.starts_with('z'),
+                // x XOR y is the half sum, combined with the carry by another XOR
+                Operation::Xor => !gate.is_first_bit() && !feeds(output, Operation::Xor),
+                // x AND y and (half sum AND carry) both feed the carry OR
+                Operation::And => !gate.is_first_bit() && !feeds(output, Operation::Or),
+                Operation::Or => false,
+            };
+
+            if misplaced {
+                swapped.insert(output.clone());
+            }
+        }
+
+        swapped.into_iter().collect()
+    }
+
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n");
+
+        for (wire, _) in self.initial_values.iter() {
+            dot.push_str(&format!("    {wire} [shape=box, color=blue];\n"));
+        }
+        for (i, gate) in self.gates.iter().enumerate() {
+            let [a, b] = &gate.inputs;
+            let operation = gate.operation.name();
+            let output = &gate.output;
+            dot.push_str(&format!(
+                "    gate{i} [label=\"{operation}\", shape=circle];\n"
+            ));
+            dot.push_str(&format!("    {a} -> gate{i};\n    {b} -> gate{i};\n"));
+            dot.push_str(&format!("    gate{i} -> {output};\n"));
+            if output.starts_with('z') {
+                dot.push_str(&format!("    {output} [shape=box, color=red];\n"));
+            }
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+impl Puzzle for Circuit {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        self.output_number()?.try_into()
+    }
+
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        Ok(self.swapped_wires().join(",").into())
+    }
+}
+
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(Circuit::from(&input)?))
+}
+
+pub fn export_dot(input: String) -> Result<String, String> {
+    Ok(Circuit::from(&input)?.to_dot())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day24;
+    use crate::day24::Circuit;
+
+    fn sample_input() -> String {
+        r#"x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02"#
+            .to_string()
+    }
+
+    /// Ripple-carry adder of `bits` bits adding `x` and `y`.
+    fn adder_input(bits: usize, x: u64, y: u64) -> String {
+        let mut lines = Vec::new();
+        for i in 0..bits {
+            lines.push(format!("x{i:02}: {}", (x >> i) & 1));
+        }
+        for i in 0..bits {
+            lines.push(format!("y{i:02}: {}", (y >> i) & 1));
+        }
+        lines.push(String::new());
+
+        lines.push("x00 XOR y00 -> z00".to_string());
+        lines.push("x00 AND y00 -> c00".to_string());
+        for i in 1..bits {
+            let carry_in = format!("c{:02}", i - 1);
+            let carry_out = if i == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            lines.push(format!("x{i:02} XOR y{i:02} -> s{i:02}"));
+            lines.push(format!("y{i:02} AND x{i:02} -> a{i:02}"));
+            lines.push(format!("s{i:02} XOR {carry_in} -> z{i:02}"));
+            lines.push(format!("{carry_in} AND s{i:02} -> b{i:02}"));
+            lines.push(format!("a{i:02} OR b{i:02} -> {carry_out}"));
+        }
+
+        lines.join("\n")
+    }
+
+    fn swap_outputs(input: &str, wire1: &str, wire2: &str) -> String {
+        input
+            .lines()
+            .map(|line| {
+                if line.ends_with(&format!("-> {wire1}")) {
+                    line.replace(&format!("-> {wire1}"), &format!("-> {wire2}"))
+                } else if line.ends_with(&format!("-> {wire2}")) {
+                    line.replace(&format!("-> {wire2}"), &format!("-> {wire1}"))
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    pub fn parse_input() {
+        Circuit::from("x00: 2\n\nx00 AND y00 -> z00").expect_err("expected Err(), got Ok(Circuit)");
+        Circuit::from("x00: 1\n\nx00 NAND y00 -> z00")
+            .expect_err("expected Err(), got Ok(Circuit)");
+
+        let circuit = Circuit::from(&sample_input()).expect("expected Ok(Circuit), got Err()");
+        assert_eq!(circuit.initial_values.len(), 6);
+        assert_eq!(circuit.gates.len(), 3);
+    }
+
+    #[test]
+    pub fn adder_output() {
+        let circuit =
+            Circuit::from(&adder_input(8, 117, 201)).expect("expected Ok(Circuit), got Err()");
+        assert_eq!(circuit.output_number(), Ok(318));
+        assert!(circuit.swapped_wires().is_empty());
+    }
+
+    #[test]
+    pub fn swapped_adder_wires() {
+        let input = adder_input(8, 0, 0);
+        let input = swap_outputs(&input, "s01", "a01");
+        let input = swap_outputs(&input, "z02", "c02");
+        let input = swap_outputs(&input, "b05", "z05");
+        let circuit = Circuit::from(&input).expect("expected Ok(Circuit), got Err()");
+
+        assert_eq!(
+            circuit.swapped_wires(),
+            vec!["a01", "b05", "c02", "s01", "z02", "z05"]
+        );
+    }
+
+    #[test]
+    pub fn dot_export() {
+        let dot = day24::export_dot(sample_input()).expect("expected Ok(String), got Err()");
+
+        assert!(dot.starts_with("digraph circuit {"));
+        assert!(dot.contains("gate1 [label=\"XOR\", shape=circle];"));
+        assert!(dot.contains("x01 -> gate1;"));
+        assert!(dot.contains("gate1 -> z01;"));
+    }
+}
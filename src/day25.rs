@@ -0,0 +1,145 @@
+use crate::utils::{Answer, Puzzle};
+
+const SCHEMATIC_WIDTH: usize = 5;
+const SCHEMATIC_HEIGHT: usize = 7;
+
+#[derive(Debug, Default)]
+struct Schematics {
+    locks: Vec<[u8; SCHEMATIC_WIDTH]>,
+    keys: Vec<[u8; SCHEMATIC_WIDTH]>,
+}
+
+impl Schematics {
+    pub fn from(input: &str) -> Result<Self, String> {
+        let mut schematics = Self::default();
+        let lines: Vec<&str> = input.lines().map(|line| line.trim()).collect();
+
+        for block in lines
+            .split(|line| line.is_empty())
+            .filter(|b| !b.is_empty())
+        {
+            if block.len() != SCHEMATIC_HEIGHT
+                || block.iter().any(|row| row.len() != SCHEMATIC_WIDTH)
+            {
+                return Err(format!(
+                    "schematic should be {SCHEMATIC_WIDTH}x{SCHEMATIC_HEIGHT}"
+                ));
+            }
+            if !block
+                .iter()
+                .all(|row| row.chars().all(|c| c == '#' || c == '.'))
+            {
+                return Err("Input contains invalid characters".to_string());
+            }
+
+            // the full top (lock) or bottom (key) row doesn't count towards the height
+            let mut heights = [0; SCHEMATIC_WIDTH];
+            for row in block[1..SCHEMATIC_HEIGHT - 1].iter() {
+                for (height, c) in heights.iter_mut().zip(row.chars()) {
+                    if c == '#' {
+                        *height += 1;
+                    }
+                }
+            }
+
+            match (block[0], block[SCHEMATIC_HEIGHT - 1]) {
+                ("#####", ".....") => schematics.locks.push(heights),
+                (".....", "#####") => schematics.keys.push(heights),
+                _ => return Err("schematic is neither a lock nor a key".to_string()),
+            }
+        }
+
+        Ok(schematics)
+    }
+
+    fn count_fitting_pairs(&self) -> i32 {
+        let space = (SCHEMATIC_HEIGHT - 2) as u8;
+
+        self.locks
+            .iter()
+            .map(|lock| {
+                self.keys
+                    .iter()
+                    .filter(|key| lock.iter().zip(key.iter()).all(|(l, k)| l + k <= space))
+                    .count() as i32
+            })
+            .sum()
+    }
+}
+
+impl Puzzle for Schematics {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        Ok(self.count_fitting_pairs().into())
+    }
+
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        // the second star is awarded for collecting all the others
+        Ok(Answer::default())
+    }
+}
+
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(Schematics::from(&input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day25::Schematics;
+
+    fn sample_input() -> String {
+        r#"#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####"#
+            .to_string()
+    }
+
+    #[test]
+    pub fn parse_input() {
+        Schematics::from("#####\n.....").expect_err("expected Err(), got Ok(Schematics)");
+
+        let schematics =
+            Schematics::from(&sample_input()).expect("expected Ok(Schematics), got Err()");
+
+        assert_eq!(schematics.locks, vec![[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]]);
+        assert_eq!(
+            schematics.keys,
+            vec![[5, 0, 2, 1, 3], [4, 3, 4, 0, 2], [3, 0, 2, 0, 1]]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::utils::Solution;
+use crate::utils::{Answer, Puzzle};
 
 enum MulState {
     Clear,
@@ -31,12 +31,35 @@ enum DontState {
     CloseBracket,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutedToken {
+    pub token: Token,
+    pub enabled: bool,
+    pub running_sum: i32,
+    pub enabled_running_sum: i32,
+}
+
 struct LexicalAnalyzer {
     mul_state: MulState,
     do_state: DoState,
     dont_state: DontState,
     buffer: String,
-    output: String,
+    position: usize,
+    tokens: Vec<Token>,
 }
 
 impl LexicalAnalyzer {
@@ -46,7 +69,8 @@ impl LexicalAnalyzer {
             do_state: DoState::Clear,
             dont_state: DontState::Clear,
             buffer: String::with_capacity(10),
-            output: String::with_capacity(100),
+            position: 0,
+            tokens: Vec::with_capacity(100),
         }
     }
 
@@ -54,6 +78,16 @@ impl LexicalAnalyzer {
         self.set_mul_state(c);
         self.set_do_state(c);
         self.set_dont_state(c);
+        self.position += c.len_utf8();
+    }
+
+    // tokens span bytes `start..end` of the input and end right before the current character
+    fn push_token(&mut self, kind: TokenKind, length: usize) {
+        self.tokens.push(Token {
+            kind,
+            start: self.position - length,
+            end: self.position,
+        });
     }
 
     fn finalize(&mut self) {
@@ -123,10 +157,9 @@ impl LexicalAnalyzer {
                 }
             }
             MulState::CloseBracket => {
-                if !self.output.is_empty() {
-                    self.output.push(' ');
+                if let Some((num1, num2)) = extract_numbers(&self.buffer) {
+                    self.push_token(TokenKind::Mul(num1, num2), self.buffer.len());
                 }
-                self.output.push_str(&self.buffer);
 
                 self.buffer.clear();
                 self.mul_state = MulState::Clear;
@@ -164,10 +197,7 @@ impl LexicalAnalyzer {
                 }
             }
             DoState::CloseBracket => {
-                if !self.output.is_empty() {
-                    self.output.push(' ');
-                }
-                self.output.push_str("do()");
+                self.push_token(TokenKind::Do, "do()".len());
                 self.do_state = DoState::Clear;
                 self.set_do_state(c);
             }
@@ -224,10 +254,7 @@ impl LexicalAnalyzer {
                 }
             }
             DontState::CloseBracket => {
-                if !self.output.is_empty() {
-                    self.output.push(' ');
-                }
-                self.output.push_str("don't()");
+                self.push_token(TokenKind::Dont, "don't()".len());
                 self.dont_state = DontState::Clear;
                 self.set_dont_state(c);
             }
@@ -244,42 +271,75 @@ impl LexicalAnalyzer {
     }
 }
 
-pub fn solve(input: String) -> Result<Solution, String> {
+struct Instructions(Vec<Token>);
+
+impl Puzzle for Instructions {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        Ok(calculate(&tokenize(&self.0, false)).into())
+    }
+
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        Ok(calculate(&tokenize(&self.0, true)).into())
+    }
+}
+
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(Instructions(lex(&input))))
+}
+
+fn lex(input: &str) -> Vec<Token> {
     let mut lexer = LexicalAnalyzer::new();
     for c in input.chars() {
         lexer.put_char(c);
     }
     lexer.finalize();
 
-    let parsed = lexer.output;
-    let tokens_basic = tokenize(&parsed, false);
-    let tokens_advanced = tokenize(&parsed, true);
+    lexer.tokens
+}
+
+pub fn execute(input: &str) -> Vec<ExecutedToken> {
+    let mut do_flag = true;
+    let mut running_sum = 0;
+    let mut enabled_running_sum = 0;
+
+    lex(input)
+        .into_iter()
+        .map(|token| {
+            match token.kind {
+                TokenKind::Do => do_flag = true,
+                TokenKind::Dont => do_flag = false,
+                TokenKind::Mul(num1, num2) => {
+                    running_sum += num1 * num2;
+                    if do_flag {
+                        enabled_running_sum += num1 * num2;
+                    }
+                }
+            }
 
-    Ok(Solution {
-        one_star_answer: calculate(&tokens_basic),
-        two_star_answer: calculate(&tokens_advanced),
-    })
+            ExecutedToken {
+                token,
+                enabled: do_flag,
+                running_sum,
+                enabled_running_sum,
+            }
+        })
+        .collect()
 }
 
 fn calculate(tokens: &[(i32, i32)]) -> i32 {
     tokens.iter().map(|pair| pair.0 * pair.1).sum()
 }
 
-fn tokenize(input: &str, toggle_support: bool) -> Vec<(i32, i32)> {
+fn tokenize(input: &[Token], toggle_support: bool) -> Vec<(i32, i32)> {
     let mut tokens = Vec::new();
     let mut do_flag = true;
 
-    for word in input.split_whitespace() {
-        if toggle_support {
-            if word == "do()" {
-                do_flag = true;
-            } else if word == "don't()" {
-                do_flag = false;
-            }
-        }
-
-        if do_flag && word.starts_with("mul(") {
-            tokens.push(extract_numbers(word).unwrap_or_default());
+    for token in input.iter() {
+        match token.kind {
+            TokenKind::Do if toggle_support => do_flag = true,
+            TokenKind::Dont if toggle_support => do_flag = false,
+            TokenKind::Mul(num1, num2) if do_flag => tokens.push((num1, num2)),
+            _ => {}
         }
     }
 
@@ -308,9 +368,7 @@ fn extract_numbers(input: &str) -> Option<(i32, i32)> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{utils, day3};
-
-    use super::{tokenize, LexicalAnalyzer};
+    use super::{execute, lex, tokenize, LexicalAnalyzer, Token, TokenKind};
 
     fn sample_input() -> String {
         return String::from(
@@ -324,8 +382,12 @@ mod tests {
         );
     }
 
-    fn real_input() -> String {
-        utils::get_input(3, "resources/inputs").expect("Cannot get input")
+    fn spanned_text(input: &str, tokens: &[Token]) -> String {
+        tokens
+            .iter()
+            .map(|token| &input[token.start..token.end])
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 
     #[test]
@@ -338,7 +400,10 @@ mod tests {
         }
         lexer.finalize();
 
-        assert_eq!(lexer.output, "mul(2,4) mul(5,5) mul(11,8) mul(8,5)");
+        assert_eq!(
+            spanned_text(&input, &lexer.tokens),
+            "mul(2,4) mul(5,5) mul(11,8) mul(8,5)"
+        );
     }
 
     #[test]
@@ -352,46 +417,59 @@ mod tests {
         lexer.finalize();
 
         assert_eq!(
-            lexer.output,
+            spanned_text(&input, &lexer.tokens),
             "mul(2,4) don't() mul(5,5) mul(11,8) do() mul(8,5)"
         );
     }
 
     #[test]
-    pub fn tokenization() {
-        let input_basic = String::from("mul(2,4) mul(5,5) mul(11,8) mul(8,5)");
-        let input_advanced = String::from("mul(2,4) don't() mul(5,5) mul(11,8) do() mul(8,5)");
-
-        let tokens1 = tokenize(&input_basic, false);
-        let tokens2 = tokenize(&input_advanced, false);
-        let tokens3 = tokenize(&input_advanced, true);
+    pub fn token_spans() {
+        let input = sample_input_2();
+        let tokens = lex(&input);
 
-        assert_eq!(vec![(2, 4), (5, 5), (11, 8), (8, 5)], tokens1);
-        assert_eq!(vec![(2, 4), (5, 5), (11, 8), (8, 5)], tokens2);
-        assert_eq!(vec![(2, 4), (8, 5)], tokens3);
+        assert_eq!(
+            tokens[0],
+            Token {
+                kind: TokenKind::Mul(2, 4),
+                start: 1,
+                end: 9,
+            }
+        );
+        assert_eq!(
+            tokens[1],
+            Token {
+                kind: TokenKind::Dont,
+                start: 20,
+                end: 27,
+            }
+        );
+        assert_eq!(tokens[5].kind, TokenKind::Mul(8, 5));
+        assert_eq!(tokens[5].end, input.len() - 1);
     }
 
     #[test]
-    pub fn sample_input_one_star_answer() {
-        let solution = day3::solve(sample_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.one_star_answer, 161);
-    }
+    pub fn execution() {
+        let executed = execute(&sample_input_2());
 
-    #[test]
-    pub fn one_star_answer() {
-        let solution = day3::solve(real_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.one_star_answer, 189600467);
-    }
+        let enabled: Vec<bool> = executed.iter().map(|token| token.enabled).collect();
+        assert_eq!(enabled, vec![true, false, false, false, true, true]);
 
-    #[test]
-    pub fn sample_input_two_star_answer() {
-        let solution = day3::solve(sample_input_2()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.two_star_answer, 48);
+        let last = executed.last().expect("expected tokens");
+        assert_eq!(last.running_sum, 161);
+        assert_eq!(last.enabled_running_sum, 48);
     }
 
     #[test]
-    pub fn two_star_answer() {
-        let solution = day3::solve(real_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.two_star_answer, 107069718);
+    pub fn tokenization() {
+        let input_basic = lex("mul(2,4) mul(5,5) mul(11,8) mul(8,5)");
+        let input_advanced = lex("mul(2,4) don't() mul(5,5) mul(11,8) do() mul(8,5)");
+
+        let tokens1 = tokenize(&input_basic, false);
+        let tokens2 = tokenize(&input_advanced, false);
+        let tokens3 = tokenize(&input_advanced, true);
+
+        assert_eq!(vec![(2, 4), (5, 5), (11, 8), (8, 5)], tokens1);
+        assert_eq!(vec![(2, 4), (5, 5), (11, 8), (8, 5)], tokens2);
+        assert_eq!(vec![(2, 4), (8, 5)], tokens3);
     }
 }
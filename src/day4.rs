@@ -1,4 +1,131 @@
-use crate::utils::Solution;
+use crate::utils::{Answer, Puzzle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Direction::North => "north",
+            Direction::NorthEast => "north-east",
+            Direction::East => "east",
+            Direction::SouthEast => "south-east",
+            Direction::South => "south",
+            Direction::SouthWest => "south-west",
+            Direction::West => "west",
+            Direction::NorthWest => "north-west",
+        }
+    }
+
+    fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    fn search(&self) -> fn(&WordSearch, i32, i32) -> bool {
+        match self {
+            Direction::North => search_xmas_north,
+            Direction::NorthEast => search_xmas_north_east,
+            Direction::East => search_xmas_east,
+            Direction::SouthEast => search_xmas_south_east,
+            Direction::South => search_xmas_south,
+            Direction::SouthWest => search_xmas_south_west,
+            Direction::West => search_xmas_west,
+            Direction::NorthWest => search_xmas_north_west,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordMatch {
+    /// `XMAS` spelled from the `X` at (`x`, `y`) towards `direction`.
+    Xmas {
+        x: i32,
+        y: i32,
+        direction: Direction,
+    },
+    /// Two crossing `MAS` centred on the `A` at (`x`, `y`).
+    XMas { x: i32, y: i32 },
+}
+
+impl WordMatch {
+    /// Filter names accepted by `WordMatch::passes_filter`.
+    pub fn filters() -> Vec<&'static str> {
+        let mut filters = vec!["all", "X-MAS"];
+        filters.extend(Direction::ALL.iter().map(|direction| direction.name()));
+        filters
+    }
+
+    pub fn passes_filter(&self, filter: &str) -> bool {
+        match self {
+            _ if filter == "all" => true,
+            WordMatch::Xmas { direction, .. } => direction.name() == filter,
+            WordMatch::XMas { .. } => filter == "X-MAS",
+        }
+    }
+
+    pub fn cells(&self) -> Vec<(i32, i32)> {
+        match *self {
+            WordMatch::Xmas { x, y, direction } => {
+                let (dx, dy) = direction.offset();
+                (0..4).map(|i| (x + i * dx, y + i * dy)).collect()
+            }
+            WordMatch::XMas { x, y } => vec![
+                (x - 1, y - 1),
+                (x + 1, y - 1),
+                (x, y),
+                (x - 1, y + 1),
+                (x + 1, y + 1),
+            ],
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            WordMatch::Xmas { x, y, direction } => {
+                format!("XMAS from ({x}, {y}) heading {}", direction.name())
+            }
+            WordMatch::XMas { x, y } => format!("X-MAS centred at ({x}, {y})"),
+        }
+    }
+}
+
+/// Every XMAS and X-MAS found in a word search, with the grid to display them on.
+#[derive(Debug)]
+pub struct WordSearchMatches {
+    pub characters: String,
+    pub width: usize,
+    pub height: usize,
+    pub matches: Vec<WordMatch>,
+}
 
 #[derive(Debug)]
 struct WordSearch {
@@ -32,6 +159,10 @@ impl WordSearch {
         allowed_chars.contains(&c)
     }
 
+    fn positions(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        (0..self.height).flat_map(|y| (0..self.width).map(move |x| (x as i32, y as i32)))
+    }
+
     fn get_at(&self, x: i32, y: i32) -> Option<char> {
         if x < 0 || x as usize >= self.width || y < 0 || y as usize >= self.height {
             return None;
@@ -43,55 +174,59 @@ impl WordSearch {
     }
 }
 
-pub fn solve(input: String) -> Result<Solution, String> {
-    let word_search = WordSearch::from(&input)?;
-    let mut one_star_answer = 0;
-    let mut two_star_answer = 0;
-    let mut x: i32 = 0;
-    let mut y: i32 = 0;
+impl Puzzle for WordSearch {
+    fn one_star_answer(&self) -> Result<Answer, String> {
+        let xmas_count: i32 = self.positions().map(|(x, y)| count_xmas(self, x, y)).sum();
+        Ok(xmas_count.into())
+    }
 
-    for c in word_search.characters.iter() {
-        match *c as char {
-            'X' => one_star_answer += count_xmas(&word_search, x, y),
-            'A' => {
-                if is_x_mas(&word_search, x, y) {
-                    two_star_answer += 1
-                }
-            }
-            _ => {}
-        }
+    fn two_star_answer(&self) -> Result<Answer, String> {
+        let x_mas_count = self
+            .positions()
+            .filter(|(x, y)| is_x_mas(self, *x, *y))
+            .count();
+        Ok((x_mas_count as i32).into())
+    }
+}
+
+pub fn parse(input: String) -> Result<Box<dyn Puzzle>, String> {
+    Ok(Box::new(WordSearch::from(&input)?))
+}
+
+pub fn find_matches(input: &str) -> Result<WordSearchMatches, String> {
+    let word_search = WordSearch::from(input)?;
+    let mut matches = Vec::new();
 
-        x += 1;
-        if x as usize == word_search.width {
-            x = 0;
-            y += 1;
+    for (x, y) in word_search.positions() {
+        for direction in xmas_directions(&word_search, x, y) {
+            matches.push(WordMatch::Xmas { x, y, direction });
+        }
+        if is_x_mas(&word_search, x, y) {
+            matches.push(WordMatch::XMas { x, y });
         }
     }
 
-    Ok(Solution {
-        one_star_answer,
-        two_star_answer,
+    Ok(WordSearchMatches {
+        characters: String::from_utf8_lossy(&word_search.characters).into_owned(),
+        width: word_search.width,
+        height: word_search.height,
+        matches,
     })
 }
 
 fn count_xmas(word_search: &WordSearch, initial_x: i32, initial_y: i32) -> i32 {
+    xmas_directions(word_search, initial_x, initial_y).len() as i32
+}
+
+fn xmas_directions(word_search: &WordSearch, initial_x: i32, initial_y: i32) -> Vec<Direction> {
     if word_search.get_at(initial_x, initial_y) != Some('X') {
-        return 0;
+        return Vec::new();
     }
 
-    [
-        search_xmas_north,
-        search_xmas_north_east,
-        search_xmas_east,
-        search_xmas_south_east,
-        search_xmas_south,
-        search_xmas_south_west,
-        search_xmas_west,
-        search_xmas_north_west,
-    ]
-    .iter()
-    .filter(|f| f(word_search, initial_x, initial_y))
-    .count() as i32
+    Direction::ALL
+        .into_iter()
+        .filter(|direction| direction.search()(word_search, initial_x, initial_y))
+        .collect()
 }
 
 fn is_x_mas(word_search: &WordSearch, initial_x: i32, initial_y: i32) -> bool {
@@ -171,12 +306,8 @@ fn search_xmas_north_west(word_search: &WordSearch, initial_x: i32, initial_y: i
 
 #[cfg(test)]
 mod tests {
-    use crate::day4::WordSearch;
-    use crate::{day4, utils};
-
-    fn real_input() -> String {
-        utils::get_input(4, "resources/inputs").expect("Cannot get input")
-    }
+    use crate::day4::{Direction, WordMatch, WordSearch};
+    use crate::day4;
 
     fn sample_input() -> String {
         r#"MMMSXXMASM
@@ -218,26 +349,40 @@ MXMXAXMASX"#
     }
 
     #[test]
-    pub fn sample_input_one_star_answer() {
-        let solution = day4::solve(sample_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.one_star_answer, 18);
-    }
-
-    #[test]
-    pub fn one_star_answer() {
-        let solution = day4::solve(real_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.one_star_answer, 2458);
-    }
-
-    #[test]
-    pub fn sample_input_two_star_answer() {
-        let solution = day4::solve(sample_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.two_star_answer, 9);
+    pub fn find_matches() {
+        let found = day4::find_matches(&sample_input()).expect("expected Ok, got Err()");
+        let xmas_count = found
+            .matches
+            .iter()
+            .filter(|m| matches!(m, WordMatch::Xmas { .. }))
+            .count();
+
+        assert_eq!(found.characters.len(), 100);
+        assert_eq!(xmas_count, 18);
+        assert_eq!(found.matches.len(), 18 + 9);
+        assert!(found.matches.contains(&WordMatch::Xmas {
+            x: 5,
+            y: 0,
+            direction: Direction::East
+        }));
+        assert!(found.matches.contains(&WordMatch::XMas { x: 2, y: 1 }));
     }
 
     #[test]
-    pub fn two_star_answer() {
-        let solution = day4::solve(real_input()).expect("expected Ok(Solution), got Err()");
-        assert_eq!(solution.two_star_answer, 1945);
+    pub fn match_filters_and_cells() {
+        let xmas = WordMatch::Xmas {
+            x: 9,
+            y: 9,
+            direction: Direction::NorthWest,
+        };
+        let x_mas = WordMatch::XMas { x: 2, y: 1 };
+
+        assert!(xmas.passes_filter("all"));
+        assert!(xmas.passes_filter("north-west"));
+        assert!(!xmas.passes_filter("X-MAS"));
+        assert!(x_mas.passes_filter("X-MAS"));
+        assert_eq!(xmas.cells(), vec![(9, 9), (8, 8), (7, 7), (6, 6)]);
+        assert!(x_mas.cells().contains(&(2, 1)));
+        assert_eq!(WordMatch::filters().len(), 10);
     }
 }
@@ -0,0 +1,102 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub label: String,
+    pub width: usize,
+    pub height: usize,
+    /// Cells in row-major order.
+    pub cells: Vec<char>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Recording {
+    frames: Vec<Frame>,
+}
+
+impl Recording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push<R>(
+        &mut self,
+        label: impl Into<String>,
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<(), String>
+    where
+        R: IntoIterator<Item = char>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for row in rows {
+            let row_start = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - row_start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err("frame rows have different lengths".to_string());
+            }
+            height += 1;
+        }
+        let width = width.ok_or("frame is empty".to_string())?;
+
+        if let Some(first) = self.frames.first() {
+            if (first.width, first.height) != (width, height) {
+                return Err("frame size differs from previous frames".to_string());
+            }
+        }
+
+        self.frames.push(Frame {
+            label: label.into(),
+            width,
+            height,
+            cells,
+        });
+        Ok(())
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Recording;
+
+    #[test]
+    pub fn push_frames() {
+        let mut recording = Recording::new();
+        recording
+            .push("start", ["#.", ".#"].iter().map(|row| row.chars()))
+            .expect("expected Ok(), got Err()");
+        recording
+            .push("end", vec![vec!['#', '#'], vec!['.', '.']])
+            .expect("expected Ok(), got Err()");
+
+        let frames = recording.frames();
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].width, frames[0].height), (2, 2));
+        assert_eq!(frames[0].cells, vec!['#', '.', '.', '#']);
+        assert_eq!(frames[1].label, "end");
+        assert_eq!(frames[1].cells[1], '#');
+    }
+
+    #[test]
+    pub fn reject_mismatched_frames() {
+        let mut recording = Recording::new();
+        recording
+            .push("ragged", ["#.", "."].iter().map(|row| row.chars()))
+            .expect_err("expected Err(), got Ok()");
+        recording
+            .push("empty", Vec::<Vec<char>>::new())
+            .expect_err("expected Err(), got Ok()");
+
+        recording
+            .push("first", ["#."].iter().map(|row| row.chars()))
+            .expect("expected Ok(), got Err()");
+        recording
+            .push("bigger", ["#..", "..."].iter().map(|row| row.chars()))
+            .expect_err("expected Err(), got Ok()");
+    }
+}
@@ -0,0 +1,237 @@
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputFile {
+    pub day_number: i32,
+    pub size: u64,
+    pub modified: Option<SystemTime>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InputsScan {
+    pub files: Vec<InputFile>,
+    pub warnings: Vec<String>,
+}
+
+impl InputsScan {
+    pub fn get(&self, day_number: i32) -> Option<&InputFile> {
+        self.files.iter().find(|file| file.day_number == day_number)
+    }
+}
+
+pub fn parse_day_number(file_name: &str) -> Option<i32> {
+    let number = file_name.strip_prefix("day")?.strip_suffix(".txt")?;
+    if number.starts_with('0') {
+        return None;
+    }
+    number.parse().ok().filter(|n| (1..=25).contains(n))
+}
+
+pub fn scan_inputs_dir(inputs_dir: &str) -> Result<InputsScan, String> {
+    let entries =
+        std::fs::read_dir(inputs_dir).map_err(|err| format!("{err}. \"{inputs_dir}\""))?;
+    let mut scan = InputsScan::default();
+
+    for entry in entries {
+        let entry = entry.map_err(|err| err.to_string())?;
+        let metadata = match entry.metadata() {
+            Ok(metadata) if metadata.is_file() => metadata,
+            _ => continue,
+        };
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+
+        match parse_day_number(&file_name) {
+            None => scan
+                .warnings
+                .push(format!("unexpected file \"{file_name}\"")),
+            Some(day_number) => {
+                if metadata.len() == 0 {
+                    scan.warnings.push(format!("\"{file_name}\" is empty"));
+                }
+                scan.files.push(InputFile {
+                    day_number,
+                    size: metadata.len(),
+                    modified: metadata.modified().ok(),
+                });
+            }
+        }
+    }
+
+    scan.files.sort_by_key(|file| file.day_number);
+    scan.warnings.sort();
+    Ok(scan)
+}
+
+const RESERVED_DIRS: [&str; 3] = ["answers", "submissions", "variants"];
+
+pub fn list_profiles(inputs_dir: &str) -> Vec<String> {
+    let mut profiles: Vec<String> = std::fs::read_dir(inputs_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| !RESERVED_DIRS.contains(&name.as_str()))
+        .filter(|name| {
+            scan_inputs_dir(&profile_dir(inputs_dir, name)).is_ok_and(|scan| !scan.files.is_empty())
+        })
+        .collect();
+    profiles.sort();
+    profiles
+}
+
+pub fn profile_dir(inputs_dir: &str, profile: &str) -> String {
+    if profile.is_empty() {
+        inputs_dir.to_string()
+    } else {
+        format!("{inputs_dir}/{profile}")
+    }
+}
+
+pub fn local_path_from_url(url: &str) -> Result<String, String> {
+    let (scheme, rest) = url
+        .split_once(':')
+        .ok_or_else(|| format!("\"{url}\" is not a URL"))?;
+    if !scheme.eq_ignore_ascii_case("file") {
+        return Err(format!(
+            "only local folders are supported, \"{url}\" is a {scheme} URL"
+        ));
+    }
+
+    // `file:/path`, `file:///path` and `file://localhost/path` are local, other hosts are not
+    let path = match rest.strip_prefix("//") {
+        None => rest,
+        Some(authority_and_path) => {
+            let (host, path) = match authority_and_path.find('/') {
+                Some(i) => authority_and_path.split_at(i),
+                None => (authority_and_path, ""),
+            };
+            if !host.is_empty() && !host.eq_ignore_ascii_case("localhost") {
+                return Err(format!(
+                    "remote folders are not supported, \"{url}\" is on {host}"
+                ));
+            }
+            path
+        }
+    };
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    if path.is_empty() {
+        return Err(format!("\"{url}\" has no path"));
+    }
+
+    percent_decode(path).ok_or_else(|| format!("\"{url}\" is not a valid file URL"))
+}
+
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        list_profiles, local_path_from_url, parse_day_number, profile_dir, scan_inputs_dir,
+    };
+
+    #[test]
+    pub fn day_numbers() {
+        assert_eq!(parse_day_number("day1.txt"), Some(1));
+        assert_eq!(parse_day_number("day25.txt"), Some(25));
+        assert_eq!(parse_day_number("day26.txt"), None);
+        assert_eq!(parse_day_number("day01.txt"), None);
+        assert_eq!(parse_day_number("day1.txt.bak"), None);
+        assert_eq!(parse_day_number("input1.txt"), None);
+    }
+
+    #[test]
+    pub fn local_paths() {
+        assert_eq!(
+            local_path_from_url("file:///home/user/aoc/inputs"),
+            Ok(String::from("/home/user/aoc/inputs"))
+        );
+        assert_eq!(
+            local_path_from_url("file:///home/user/my%20inputs/100%25"),
+            Ok(String::from("/home/user/my inputs/100%"))
+        );
+        assert_eq!(
+            local_path_from_url("file://localhost/home/u\u{017c}ytkownik"),
+            Ok(String::from("/home/u\u{017c}ytkownik"))
+        );
+        assert_eq!(
+            local_path_from_url("file:///home/%C5%BC"),
+            Ok(String::from("/home/\u{017c}"))
+        );
+
+        local_path_from_url("smb://server/share").expect_err("expected Err(), got Ok(path)");
+        local_path_from_url("file://server/share").expect_err("expected Err(), got Ok(path)");
+        local_path_from_url("file:///broken%2").expect_err("expected Err(), got Ok(path)");
+        local_path_from_url("/home/user").expect_err("expected Err(), got Ok(path)");
+    }
+
+    #[test]
+    pub fn scan() {
+        let inputs_dir = std::env::temp_dir().join(format!("aoc24-scan-{}", std::process::id()));
+        std::fs::create_dir_all(inputs_dir.join("answers")).expect("cannot create directory");
+        for (file_name, content) in [
+            ("day2.txt", "1 2 3\n"),
+            ("day1.txt", "3   4\n"),
+            ("day3.txt", ""),
+            ("notes.md", "todo"),
+        ] {
+            std::fs::write(inputs_dir.join(file_name), content).expect("cannot write file");
+        }
+
+        let scan = scan_inputs_dir(inputs_dir.to_str().expect("path is not UTF-8"))
+            .expect("expected Ok(InputsScan), got Err()");
+        std::fs::remove_dir_all(&inputs_dir).expect("cannot clean up");
+
+        let days: Vec<i32> = scan.files.iter().map(|file| file.day_number).collect();
+        assert_eq!(days, vec![1, 2, 3]);
+        assert_eq!(scan.get(2).map(|file| file.size), Some(6));
+        assert!(scan.get(4).is_none());
+        assert_eq!(
+            scan.warnings,
+            vec!["\"day3.txt\" is empty", "unexpected file \"notes.md\""]
+        );
+
+        scan_inputs_dir("/nonexistent/inputs").expect_err("expected Err(), got Ok(InputsScan)");
+    }
+
+    #[test]
+    pub fn profiles() {
+        let inputs_dir =
+            std::env::temp_dir().join(format!("aoc24-profiles-{}", std::process::id()));
+        for (dir, file_name) in [
+            ("bob", "day3.txt"),
+            ("alice", "day3.txt"),
+            ("answers", "day3.txt"),
+            ("variants", "day3-small.txt"),
+            ("notes", "todo.md"),
+        ] {
+            std::fs::create_dir_all(inputs_dir.join(dir)).expect("cannot create directory");
+            std::fs::write(inputs_dir.join(dir).join(file_name), "mul(2,4)")
+                .expect("cannot write file");
+        }
+
+        let inputs_dir_str = inputs_dir.to_str().expect("path is not UTF-8");
+        let profiles = list_profiles(inputs_dir_str);
+        std::fs::remove_dir_all(&inputs_dir).expect("cannot clean up");
+
+        assert_eq!(profiles, vec!["alice", "bob"]);
+        assert_eq!(profile_dir("inputs", "alice"), "inputs/alice");
+        assert_eq!(profile_dir("inputs", ""), "inputs");
+    }
+}
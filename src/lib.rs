@@ -0,0 +1,13 @@
+pub mod utils;
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod frames;
+pub mod registry;
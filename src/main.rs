@@ -1,21 +1,313 @@
-mod utils;
-mod day1;
-mod day2;
-mod day3;
-mod day4;
+mod client;
+mod inputs;
+mod profiling;
+mod render;
+mod samples;
+mod settings;
+#[cfg(test)]
+mod stand_in;
+mod submissions;
+mod watch;
 mod qml_types;
 
+use aoc24::{day1, day2, day3, day4, day24, frames, registry, utils};
 use qmetaobject::{prelude::*, QUrl};
+use profiling::CountingAllocator;
 use qml_types::register_all_qml_types;
+use render::Palette;
+use utils::is_single_star_day;
+use watch::InputsWatcher;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 fn init_qrc() {
     qrc!(compile_qml_files, "qml" {
-        "resources/qml/mainwindow.qml" as "mainwindow"
+        "resources/qml/mainwindow.qml" as "mainwindow",
+        "resources/qml/Day1View.qml" as "Day1View.qml",
+        "resources/qml/Day2View.qml" as "Day2View.qml",
+        "resources/qml/Day3View.qml" as "Day3View.qml",
+        "resources/qml/Day4View.qml" as "Day4View.qml",
+        "resources/qml/FramePlayerView.qml" as "FramePlayerView.qml"
     });
     compile_qml_files();
 }
 
+fn run_command(args: &[String]) -> Result<(), String> {
+    match args {
+        [command, inputs_dir] if command == "day24-dot" => {
+            let input = utils::get_input(24, inputs_dir)?;
+            print!("{}", day24::export_dot(input)?);
+            Ok(())
+        }
+        [command, day_number, inputs_dir] if command == "solve" => {
+            let day_number: i32 = day_number
+                .parse()
+                .or(Err(format!("invalid day number \"{day_number}\"")))?;
+            print_solution(day_number, inputs_dir)
+        }
+        [command, day_number, inputs_dir, option] if command == "solve" && option == "--watch" => {
+            let day_number: i32 = day_number
+                .parse()
+                .or(Err(format!("invalid day number \"{day_number}\"")))?;
+            watch_solution(day_number, inputs_dir)
+        }
+        [command, day_number, inputs_dir] if command == "solve-profiles" => {
+            let day_number: i32 = day_number
+                .parse()
+                .or(Err(format!("invalid day number \"{day_number}\"")))?;
+            print_profile_solutions(day_number, inputs_dir)
+        }
+        [command, days, inputs_dir] if command == "download" => download_inputs(days, inputs_dir),
+        [command, day_number, part, inputs_dir, answer @ ..]
+            if command == "submit" && answer.len() <= 1 =>
+        {
+            let day_number: i32 = day_number
+                .parse()
+                .or(Err(format!("invalid day number \"{day_number}\"")))?;
+            let part: i32 = part.parse().or(Err(format!("invalid part \"{part}\"")))?;
+            submit_answer(day_number, part, inputs_dir, answer.first())
+        }
+        [command, day_number, page, samples_dir @ ..]
+            if command == "extract-samples" && samples_dir.len() <= 1 =>
+        {
+            let day_number: i32 = day_number
+                .parse()
+                .or(Err(format!("invalid day number \"{day_number}\"")))?;
+            let samples_dir = samples_dir
+                .first()
+                .map_or(samples::DEFAULT_SAMPLES_DIR, String::as_str);
+            extract_samples(day_number, page, samples_dir)
+        }
+        [command, day_number, inputs_dir, output, options @ ..] if command == "render" => {
+            let day_number: i32 = day_number
+                .parse()
+                .or(Err(format!("invalid day number \"{day_number}\"")))?;
+            let recorder = registry::get_recorder(day_number)?;
+            let recording = recorder(utils::get_input(day_number, inputs_dir)?)?;
+            render_recording(&recording, output, options)
+        }
+        _ => Err(String::from(
+            "usage: AOC24 [solve <day> <inputs_dir> [--watch] | solve-profiles <day> <inputs_dir> | \
+             day24-dot <inputs_dir> | download <day | all> <inputs_dir> | \
+             submit <day> <part> <inputs_dir> [answer] | \
+             extract-samples <day> <page.html> [samples_dir] | \
+             render <day> <inputs_dir> <output.gif | output_dir> \
+             [--palette <char>=<rrggbb>,...] [--cell-size <px>] [--fps <n>]]",
+        )),
+    }
+}
+
+fn print_solution(day_number: i32, inputs_dir: &str) -> Result<(), String> {
+    let parser = registry::get_parser(day_number)?;
+    let solution = parser(utils::get_input(day_number, inputs_dir)?)?.solve()?;
+    println!("One star solution: {}", solution.one_star_answer);
+    if !is_single_star_day(day_number) {
+        println!("Two star solution: {}", solution.two_star_answer);
+    }
+    Ok(())
+}
+
+/// Prints a table with the answers for the inputs directory and every profile in it.
+fn print_profile_solutions(day_number: i32, inputs_dir: &str) -> Result<(), String> {
+    let parser = registry::get_parser(day_number)?;
+    let mut profiles = inputs::list_profiles(inputs_dir);
+    if std::path::Path::new(&format!("{inputs_dir}/day{day_number}.txt")).is_file() {
+        profiles.insert(0, String::new());
+    }
+    if profiles.is_empty() {
+        return Err(format!(
+            "no inputs for day {day_number} in \"{inputs_dir}\""
+        ));
+    }
+
+    let mut header = vec![String::from("Profile"), String::from("One star")];
+    if !is_single_star_day(day_number) {
+        header.push(String::from("Two star"));
+    }
+    let rows: Vec<Vec<String>> = profiles
+        .iter()
+        .map(|profile| {
+            let name = match profile.as_str() {
+                "" => String::from("(default)"),
+                profile => profile.to_string(),
+            };
+            let solution = utils::get_input(day_number, &inputs::profile_dir(inputs_dir, profile))
+                .and_then(parser)
+                .and_then(|puzzle| puzzle.solve());
+            match solution {
+                Ok(solution) if is_single_star_day(day_number) => {
+                    vec![name, solution.one_star_answer.to_string()]
+                }
+                Ok(solution) => vec![
+                    name,
+                    solution.one_star_answer.to_string(),
+                    solution.two_star_answer.to_string(),
+                ],
+                Err(msg) => vec![name, format!("error: {msg}")],
+            }
+        })
+        .collect();
+    let table: Vec<&Vec<String>> = [&header].into_iter().chain(rows.iter()).collect();
+
+    // the last cell of a row is never padded, so long error messages don't widen the columns
+    let mut widths = vec![0; header.len()];
+    for row in table.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter().take(row.len() - 1)) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in table.iter() {
+        let mut line = String::new();
+        for (i, cell) in row.iter().enumerate() {
+            if i + 1 < row.len() {
+                line.push_str(&format!("{cell:<width$}  ", width = widths[i]));
+            } else {
+                line.push_str(cell);
+            }
+        }
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// Solves the day again every time its input is saved, until interrupted.
+fn watch_solution(day_number: i32, inputs_dir: &str) -> Result<(), String> {
+    registry::get_parser(day_number)?;
+    let mut watcher = InputsWatcher::new(inputs_dir)?;
+
+    loop {
+        if let Err(msg) = print_solution(day_number, inputs_dir) {
+            eprintln!("{msg}");
+        }
+        println!("Watching {inputs_dir}/day{day_number}.txt for changes...");
+
+        while !watcher.wait_for_changes()?.contains(&day_number) {}
+        println!();
+    }
+}
+
+/// Downloads missing inputs, with the session cookie from `$AOC_SESSION` or the config directory.
+fn download_inputs(days: &str, inputs_dir: &str) -> Result<(), String> {
+    let day_numbers: Vec<i32> = match days {
+        "all" => registry::DAYS.iter().map(|day| day.number).collect(),
+        day_number => vec![day_number
+            .parse()
+            .or(Err(format!("invalid day number \"{day_number}\"")))?],
+    };
+    let mut client = client::Client::from_env()?;
+
+    for day_number in day_numbers {
+        match client.download_input(day_number, inputs_dir)? {
+            client::Download::Saved(path) => println!("Saved {}", path.display()),
+            client::Download::AlreadyPresent(path) => {
+                println!("Skipped {}, already present", path.display())
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Submits the given answer, or the one solved from the day's input when none is given.
+fn submit_answer(
+    day_number: i32,
+    part: i32,
+    inputs_dir: &str,
+    answer: Option<&String>,
+) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let parser = registry::get_parser(day_number)?;
+            let solution = parser(utils::get_input(day_number, inputs_dir)?)?.solve()?;
+            match part {
+                1 => solution.one_star_answer.to_string(),
+                _ => solution.two_star_answer.to_string(),
+            }
+        }
+    };
+    let mut client = client::Client::from_env()?;
+
+    let verdict = submissions::submit(&mut client, day_number, part, &answer, inputs_dir)?;
+    println!("Day {day_number} part {part}, {answer}: {verdict}");
+    Ok(())
+}
+
+fn extract_samples(day_number: i32, page: &str, samples_dir: &str) -> Result<(), String> {
+    let html = std::fs::read_to_string(page).map_err(|err| format!("{err}. \"{page}\""))?;
+    let samples = samples::extract_samples(&html)?;
+
+    let paths = samples::write_samples(day_number, &samples, samples_dir)?;
+    for (path, sample) in paths.iter().zip(samples.iter()) {
+        let answers = [&sample.one_star_answer, &sample.two_star_answer]
+            .map(|answer| answer.as_deref().unwrap_or("-"));
+        println!(
+            "Wrote {}, expecting {} and {}",
+            path.display(),
+            answers[0],
+            answers[1]
+        );
+    }
+    Ok(())
+}
+
+fn render_recording(
+    recording: &frames::Recording,
+    output: &str,
+    options: &[String],
+) -> Result<(), String> {
+    let mut palette = Palette::default();
+    let mut cell_size = 4;
+    let mut fps = 10;
+
+    for option in options.chunks(2) {
+        match option {
+            [name, value] if name == "--palette" => palette.extend(value)?,
+            [name, value] if name == "--cell-size" => {
+                cell_size = value
+                    .parse()
+                    .or(Err(format!("invalid cell size \"{value}\"")))?
+            }
+            [name, value] if name == "--fps" => {
+                fps = value
+                    .parse()
+                    .ok()
+                    .filter(|fps| *fps > 0)
+                    .ok_or(format!("invalid frame rate \"{value}\""))?
+            }
+            _ => return Err(format!("unknown option \"{}\"", option.join(" "))),
+        }
+    }
+
+    let output = std::path::Path::new(output);
+    if output
+        .extension()
+        .is_some_and(|extension| extension == "gif")
+    {
+        render::write_gif(recording, &palette, cell_size, (100 / fps).max(1), output)?;
+        println!(
+            "Wrote {} frames to {}",
+            recording.frames().len(),
+            output.display()
+        );
+    } else {
+        let paths = render::write_png_sequence(recording, &palette, cell_size, output)?;
+        println!("Wrote {} frames to {}", paths.len(), output.display());
+    }
+    Ok(())
+}
+
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        if let Err(msg) = run_command(&args) {
+            eprintln!("{msg}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     register_all_qml_types();
     init_qrc();
 
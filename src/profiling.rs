@@ -0,0 +1,168 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use crate::registry::Parser;
+use crate::utils::Solution;
+
+// Counters of the current thread, only updated while `COUNTING` is set. Memory freed on the
+// thread but allocated before counting started makes `ALLOCATED_BYTES` negative.
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+    static ALLOCATED_BYTES: Cell<isize> = const { Cell::new(0) };
+    static PEAK_BYTES: Cell<isize> = const { Cell::new(0) };
+}
+
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn add(size: usize) {
+        let _ = COUNTING.try_with(|counting| {
+            if counting.get() {
+                let allocated = ALLOCATED_BYTES.get() + size as isize;
+                ALLOCATED_BYTES.set(allocated);
+                PEAK_BYTES.set(PEAK_BYTES.get().max(allocated));
+            }
+        });
+    }
+
+    fn sub(size: usize) {
+        let _ = COUNTING.try_with(|counting| {
+            if counting.get() {
+                ALLOCATED_BYTES.set(ALLOCATED_BYTES.get() - size as isize);
+            }
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::sub(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                Self::add(new_size - layout.size());
+            } else {
+                Self::sub(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Measurements {
+    pub parse_time: Duration,
+    pub one_star_time: Duration,
+    pub two_star_time: Duration,
+    pub peak_bytes: usize,
+}
+
+/// Solves the input on a thread of its own, so allocations of other threads, e.g. the GUI,
+/// are not counted in the peak.
+pub fn measure_solution(parser: Parser, input: String) -> Result<(Solution, Measurements), String> {
+    std::thread::spawn(move || {
+        ALLOCATED_BYTES.set(0);
+        PEAK_BYTES.set(0);
+        COUNTING.set(true);
+        let result = solve_measured(parser, input);
+        COUNTING.set(false);
+        result
+    })
+    .join()
+    .unwrap_or(Err(String::from("solver panicked")))
+}
+
+fn solve_measured(parser: Parser, input: String) -> Result<(Solution, Measurements), String> {
+    let start = Instant::now();
+    let puzzle = parser(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let one_star_answer = puzzle.one_star_answer()?;
+    let one_star_time = start.elapsed();
+
+    let start = Instant::now();
+    let two_star_answer = puzzle.two_star_answer()?;
+    let two_star_time = start.elapsed();
+
+    let solution = Solution {
+        one_star_answer,
+        two_star_answer,
+    };
+    let measurements = Measurements {
+        parse_time,
+        one_star_time,
+        two_star_time,
+        peak_bytes: PEAK_BYTES.get() as usize,
+    };
+
+    Ok((solution, measurements))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{Answer, Puzzle};
+
+    use super::measure_solution;
+
+    const BUFFER_SIZE: usize = 1 << 20;
+
+    struct Buffer(Vec<u8>);
+
+    impl Puzzle for Buffer {
+        fn one_star_answer(&self) -> Result<Answer, String> {
+            Ok((self.0.len() as i32).into())
+        }
+
+        fn two_star_answer(&self) -> Result<Answer, String> {
+            Ok(0.into())
+        }
+    }
+
+    fn parse(_: String) -> Result<Box<dyn Puzzle>, String> {
+        Ok(Box::new(Buffer(vec![1; BUFFER_SIZE])))
+    }
+
+    #[test]
+    pub fn only_solving_thread_is_counted() {
+        let (allocated, release) = std::sync::mpsc::channel();
+        let (hold, held) = std::sync::mpsc::channel::<()>();
+        let other_thread = std::thread::spawn(move || {
+            let buffer = vec![1u8; 64 * BUFFER_SIZE];
+            allocated.send(()).unwrap();
+            let _ = held.recv();
+            buffer.len()
+        });
+        release.recv().unwrap();
+
+        let (solution, measurements) = measure_solution(parse, String::from("input"))
+            .expect("expected Ok(Solution), got Err()");
+        hold.send(()).unwrap();
+        other_thread.join().unwrap();
+
+        assert_eq!(solution.one_star_answer, BUFFER_SIZE as i32);
+        assert!(measurements.peak_bytes >= BUFFER_SIZE);
+        assert!(measurements.peak_bytes < 2 * BUFFER_SIZE);
+    }
+}
@@ -1,44 +1,358 @@
 use qmetaobject::prelude::*;
+use qmetaobject::{QUrl, SimpleListItem, SimpleListModel};
 use cstr::cstr;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
 
-use crate::utils::{get_input, Solution};
-use crate::{day1, day2, day3, day4};
+use crate::client::Client;
+use crate::day1::{breakdown, Breakdown};
+use crate::day2::{diagnose_reports, Diagnostic};
+use crate::day3::{execute, ExecutedToken, TokenKind};
+use crate::day4::{find_matches, WordMatch, WordSearchMatches};
+use crate::frames::Recording;
+use crate::inputs::{list_profiles, local_path_from_url, profile_dir, scan_inputs_dir, InputsScan};
+use crate::profiling::{measure_solution, Measurements};
+use crate::registry::{get_parser, get_recorder, DAYS};
+use crate::settings::Settings;
+use crate::submissions::submit;
+use crate::utils::{
+    get_expected_answers, get_input, get_variant, get_variant_names, is_single_star_day,
+    save_variant, Solution,
+};
+use crate::watch::InputsWatcher;
+
+#[derive(SimpleListItem, Default, Clone)]
+pub struct DayItem {
+    pub number: i32,
+    pub title: QString,
+    pub implemented: bool,
+    pub input_present: bool,
+    pub input_size: u64,
+    /// Milliseconds since the Unix epoch, zero when unknown.
+    pub input_modified: f64,
+    pub cached: bool,
+    pub verified: bool,
+}
 
 #[derive(QObject, Default)]
 pub struct AOC24Solutions {
     // fields
     base: qt_base_class!(trait QObject),
-    solutions: HashMap<i32, Solution>,
-    inputs_dir: qt_property!(QString;),
-    solution1: qt_property!(i32; NOTIFY solution1_changed),
-    solution2: qt_property!(i32; NOTIFY solution2_changed),
+    solutions: HashMap<i32, (Solution, Measurements)>,
+    inputs_dir: qt_property!(QString; WRITE set_inputs_dir NOTIFY inputs_dir_changed),
+    profile: qt_property!(QString; WRITE set_profile NOTIFY profile_changed),
+    profiles: qt_property!(QVariantList; NOTIFY profile_changed),
+    /// Directory of the selected profile's inputs, the inputs directory itself by default.
+    active_inputs_dir: qt_property!(QString; NOTIFY inputs_dir_changed),
+    days: qt_property!(RefCell<SimpleListModel<DayItem>>; CONST),
+    input_warnings: qt_property!(QVariantList; NOTIFY input_warnings_changed),
+    solution1: qt_property!(QString; NOTIFY solution1_changed),
+    solution2: qt_property!(QString; NOTIFY solution2_changed),
+    single_star: qt_property!(bool; NOTIFY single_star_changed),
+    parse_time_ms: qt_property!(f64; NOTIFY measurements_changed),
+    one_star_time_ms: qt_property!(f64; NOTIFY measurements_changed),
+    two_star_time_ms: qt_property!(f64; NOTIFY measurements_changed),
+    peak_bytes: qt_property!(u64; NOTIFY measurements_changed),
     error_msg: qt_property!(QString; NOTIFY error_msg_changed),
+    window_x: qt_property!(i32; NOTIFY settings_changed),
+    window_y: qt_property!(i32; NOTIFY settings_changed),
+    window_width: qt_property!(i32; NOTIFY settings_changed),
+    window_height: qt_property!(i32; NOTIFY settings_changed),
+    last_day: qt_property!(i32; NOTIFY settings_changed),
+    theme: qt_property!(QString; NOTIFY settings_changed),
+    watcher: Option<InputsWatcher>,
+    watching: qt_property!(bool; WRITE set_watching NOTIFY watching_changed),
+    client: Option<Client>,
+    submission_status: qt_property!(QString; NOTIFY submission_status_changed),
+    // whether the displayed answers are the cached ones of `last_day`'s input file
+    submittable: qt_property!(bool; NOTIFY submittable_changed),
 
     // signals
+    inputs_dir_changed: qt_signal!(),
+    profile_changed: qt_signal!(),
+    input_warnings_changed: qt_signal!(),
     solution1_changed: qt_signal!(),
     solution2_changed: qt_signal!(),
+    single_star_changed: qt_signal!(),
+    measurements_changed: qt_signal!(),
     error_msg_changed: qt_signal!(),
+    settings_changed: qt_signal!(),
+    watching_changed: qt_signal!(),
+    submission_status_changed: qt_signal!(),
+    submittable_changed: qt_signal!(),
 
     // slots
     request_solution: qt_method!(
         fn request_solution(&mut self, day_number: i32) {
+            self.last_day = day_number;
+            self.settings_changed();
+            self.submission_status = QString::default();
+            self.submission_status_changed();
             self.request_solution_impl(day_number);
+            self.submittable = self.solutions.contains_key(&day_number);
+            self.submittable_changed();
+            self.refresh_days();
             self.solution1_changed();
             self.solution2_changed();
+            self.single_star_changed();
+            self.measurements_changed();
             self.error_msg_changed();
         }
     ),
+    refresh_days: qt_method!(
+        fn refresh_days(&mut self) {
+            let inputs_dir = self.active_inputs_dir.to_string();
+            let scan = if inputs_dir.is_empty() {
+                Err(String::from("no inputs directory selected"))
+            } else {
+                scan_inputs_dir(&inputs_dir)
+            };
+            let warnings = match &scan {
+                Ok(scan) => scan.warnings.clone(),
+                Err(msg) => vec![msg.clone()],
+            };
+
+            let days = self.create_day_items(&scan.unwrap_or_default());
+            self.days.borrow_mut().reset_data(days);
+            self.profiles = list_profiles(&self.inputs_dir.to_string())
+                .into_iter()
+                .map(QString::from)
+                .collect();
+            self.profile_changed();
+            self.input_warnings = warnings.into_iter().map(QString::from).collect();
+            self.input_warnings_changed();
+        }
+    ),
+    set_inputs_dir_url: qt_method!(
+        fn set_inputs_dir_url(&mut self, url: QUrl) {
+            match local_path_from_url(&QString::from(url).to_string()) {
+                Ok(path) => self.set_inputs_dir(QString::from(path)),
+                Err(msg) => {
+                    self.error_msg = QString::from(msg);
+                    self.error_msg_changed();
+                }
+            }
+        }
+    ),
+    poll_changes: qt_method!(
+        fn poll_changes(&mut self) {
+            let changed_days = match self.watcher.as_mut().map(InputsWatcher::changed_days) {
+                None => return,
+                Some(Ok(days)) => days,
+                Some(Err(msg)) => {
+                    self.error_msg = QString::from(msg);
+                    self.error_msg_changed();
+                    return;
+                }
+            };
+            if changed_days.is_empty() {
+                return;
+            }
+
+            for day_number in changed_days.iter() {
+                // only days solved before are worth solving again
+                if self.solutions.remove(day_number).is_some() && *day_number != self.last_day {
+                    if let Ok(solution) = self.create_solution(*day_number) {
+                        self.solutions.insert(*day_number, solution);
+                    }
+                }
+            }
+            if changed_days.contains(&self.last_day) {
+                self.request_solution(self.last_day);
+            } else {
+                self.refresh_days();
+            }
+        }
+    ),
+    load_settings: qt_method!(
+        fn load_settings(&mut self) {
+            let settings = Settings::load();
+            self.window_x = settings.window_x;
+            self.window_y = settings.window_y;
+            self.window_width = settings.window_width;
+            self.window_height = settings.window_height;
+            self.last_day = settings.last_day;
+            self.theme = QString::from(settings.theme);
+            self.settings_changed();
+            self.set_inputs_dir(QString::from(settings.inputs_dir.as_str()));
+
+            let input_path = format!("{}/day{}.txt", settings.inputs_dir, settings.last_day);
+            if get_parser(settings.last_day).is_ok() && Path::new(&input_path).is_file() {
+                self.request_solution(settings.last_day);
+            }
+        }
+    ),
+    save_settings: qt_method!(
+        fn save_settings(&self) {
+            let settings = Settings {
+                inputs_dir: self.inputs_dir.to_string(),
+                window_x: self.window_x,
+                window_y: self.window_y,
+                window_width: self.window_width,
+                window_height: self.window_height,
+                last_day: self.last_day,
+                theme: self.theme.to_string(),
+            };
+            if let Err(msg) = settings.save() {
+                eprintln!("cannot save settings: {msg}");
+            }
+        }
+    ),
+    solve_text: qt_method!(
+        fn solve_text(&mut self, day_number: i32, input: QString) {
+            // edited inputs are solved on the spot and never cached
+            match get_parser(day_number)
+                .and_then(|parser| measure_solution(parser, input.to_string()))
+            {
+                Err(msg) => self.error_msg = QString::from(msg),
+                Ok(solution) => self.show_solution(day_number, &solution),
+            }
+            self.submittable = false;
+            self.submittable_changed();
+            self.submission_status = QString::default();
+            self.submission_status_changed();
+            self.solution1_changed();
+            self.solution2_changed();
+            self.single_star_changed();
+            self.measurements_changed();
+            self.error_msg_changed();
+        }
+    ),
+    load_text: qt_method!(
+        fn load_text(&mut self, day_number: i32, variant: QString) -> QString {
+            let inputs_dir = self.active_inputs_dir.to_string();
+            let input = match variant.to_string() {
+                variant if variant.is_empty() => get_input(day_number, &inputs_dir),
+                variant => get_variant(day_number, &variant, &inputs_dir),
+            };
+            match input {
+                Ok(input) => QString::from(input),
+                Err(msg) => {
+                    self.error_msg = QString::from(msg);
+                    self.error_msg_changed();
+                    QString::default()
+                }
+            }
+        }
+    ),
+    save_text: qt_method!(
+        fn save_text(&mut self, day_number: i32, variant: QString, input: QString) -> bool {
+            let inputs_dir = self.active_inputs_dir.to_string();
+            let variant = variant.to_string();
+            let saved = save_variant(day_number, &variant, &inputs_dir, &input.to_string());
+            self.error_msg = QString::from(saved.clone().err().unwrap_or_default());
+            self.error_msg_changed();
+            saved.is_ok()
+        }
+    ),
+    submit_answer: qt_method!(
+        fn submit_answer(&mut self, part: i32) {
+            self.submission_status = QString::from(match self.submit_answer_impl(part) {
+                Ok(status) => status,
+                Err(msg) => format!("Not submitted: {msg}"),
+            });
+            self.submission_status_changed();
+            self.refresh_days();
+        }
+    ),
+    variant_names: qt_method!(
+        fn variant_names(&self, day_number: i32) -> QVariantList {
+            get_variant_names(day_number, &self.active_inputs_dir.to_string())
+                .into_iter()
+                .map(QString::from)
+                .collect()
+        }
+    ),
 }
 
 impl AOC24Solutions {
-    fn request_solution_impl(&mut self, day_number: i32) {
-        match self.solutions.get(&day_number) {
-            Some(solution) => {
-                self.solution1 = solution.one_star_answer;
-                self.solution2 = solution.two_star_answer;
-                self.error_msg = QString::default();
+    fn set_inputs_dir(&mut self, inputs_dir: QString) {
+        if inputs_dir != self.inputs_dir {
+            // answers computed from the previous directory's inputs are no longer valid
+            self.solutions.clear();
+            self.profile = QString::default();
+        }
+        self.inputs_dir = inputs_dir;
+        self.update_active_inputs_dir();
+    }
+
+    fn set_profile(&mut self, profile: QString) {
+        if profile != self.profile {
+            self.solutions.clear();
+        }
+        self.profile = profile;
+        self.update_active_inputs_dir();
+    }
+
+    fn update_active_inputs_dir(&mut self) {
+        let inputs_dir = self.inputs_dir.to_string();
+        self.active_inputs_dir = QString::from(profile_dir(&inputs_dir, &self.profile.to_string()));
+        if self.watching {
+            self.set_watching(true);
+        }
+        self.refresh_days();
+        self.inputs_dir_changed();
+    }
+
+    fn set_watching(&mut self, watching: bool) {
+        self.watcher = None;
+        if watching {
+            match InputsWatcher::new(&self.active_inputs_dir.to_string()) {
+                Ok(watcher) => self.watcher = Some(watcher),
+                Err(msg) => {
+                    self.error_msg = QString::from(msg);
+                    self.error_msg_changed();
+                }
             }
+        }
+        self.watching = self.watcher.is_some();
+        self.watching_changed();
+    }
+
+    fn show_solution(
+        &mut self,
+        day_number: i32,
+        (solution, measurements): &(Solution, Measurements),
+    ) {
+        self.solution1 = QString::from(solution.one_star_answer.to_string());
+        self.solution2 = QString::from(solution.two_star_answer.to_string());
+        self.single_star = is_single_star_day(day_number);
+        self.parse_time_ms = measurements.parse_time.as_secs_f64() * 1000.0;
+        self.one_star_time_ms = measurements.one_star_time.as_secs_f64() * 1000.0;
+        self.two_star_time_ms = measurements.two_star_time.as_secs_f64() * 1000.0;
+        self.peak_bytes = measurements.peak_bytes as u64;
+        self.error_msg = QString::default();
+    }
+
+    /// Submits the answer solved from the selected day's input file, never an edited one.
+    fn submit_answer_impl(&mut self, part: i32) -> Result<String, String> {
+        if !self.submittable {
+            return Err(String::from("the displayed answers were not solved from the input file"));
+        }
+        let day_number = self.last_day;
+        let (solution, _) = self
+            .solutions
+            .get(&day_number)
+            .ok_or(format!("day {day_number} is not solved yet"))?;
+        let answer = match part {
+            1 => solution.one_star_answer.to_string(),
+            _ => solution.two_star_answer.to_string(),
+        };
+
+        let client = match self.client.as_mut() {
+            Some(client) => client,
+            None => self.client.insert(Client::from_env()?),
+        };
+        let inputs_dir = self.active_inputs_dir.to_string();
+        let verdict = submit(client, day_number, part, &answer, &inputs_dir)?;
+        Ok(format!("Day {day_number} part {part}, {answer}: {verdict}"))
+    }
+
+    fn request_solution_impl(&mut self, day_number: i32) {
+        match self.solutions.get(&day_number).cloned() {
+            Some(solution) => self.show_solution(day_number, &solution),
             None => match self.create_solution(day_number) {
                 Err(msg) => self.error_msg = QString::from(msg),
                 Ok(solution) => {
@@ -49,18 +363,513 @@ impl AOC24Solutions {
         }
     }
 
-    fn create_solution(&mut self, day_number: i32) -> Result<Solution, String> {
-        let input = get_input(day_number, &self.inputs_dir.to_string())?;
-        match day_number {
-            1 => day1::solve(input),
-            2 => day2::solve(input),
-            3 => day3::solve(input),
-            4 => day4::solve(input),
-            _ => Err(String::from("solution for given day not implemented")),
+    fn create_solution(&mut self, day_number: i32) -> Result<(Solution, Measurements), String> {
+        let parser = get_parser(day_number)?;
+        let input = get_input(day_number, &self.active_inputs_dir.to_string())?;
+        measure_solution(parser, input)
+    }
+
+    fn create_day_items(&self, scan: &InputsScan) -> Vec<DayItem> {
+        let inputs_dir = self.active_inputs_dir.to_string();
+
+        DAYS.iter()
+            .map(|day| {
+                let input = scan.get(day.number);
+                let solution = self
+                    .solutions
+                    .get(&day.number)
+                    .map(|(solution, _)| solution);
+                let verified = match (solution, get_expected_answers(day.number, &inputs_dir)) {
+                    (Some(solution), Some(expected)) => solution.matches(&expected),
+                    _ => false,
+                };
+
+                DayItem {
+                    number: day.number,
+                    title: QString::from(day.title),
+                    implemented: day.parser.is_some(),
+                    input_present: input.is_some(),
+                    input_size: input.map_or(0, |input| input.size),
+                    input_modified: input
+                        .and_then(|input| input.modified?.duration_since(UNIX_EPOCH).ok())
+                        .map_or(0.0, |modified| modified.as_secs_f64() * 1000.0),
+                    cached: solution.is_some(),
+                    verified,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(QObject, Default)]
+pub struct WordSearchView {
+    // fields
+    base: qt_base_class!(trait QObject),
+    found: Option<WordSearchMatches>,
+    characters: qt_property!(QString; NOTIFY grid_changed),
+    grid_width: qt_property!(i32; NOTIFY grid_changed),
+    grid_height: qt_property!(i32; NOTIFY grid_changed),
+    filter: qt_property!(QString; WRITE set_filter NOTIFY filter_changed),
+    highlights: qt_property!(QVariantList; NOTIFY highlights_changed),
+    match_count: qt_property!(i32; NOTIFY highlights_changed),
+    selected_matches: qt_property!(QVariantList; NOTIFY selected_matches_changed),
+    error_msg: qt_property!(QString; NOTIFY error_msg_changed),
+
+    // signals
+    grid_changed: qt_signal!(),
+    filter_changed: qt_signal!(),
+    highlights_changed: qt_signal!(),
+    selected_matches_changed: qt_signal!(),
+    error_msg_changed: qt_signal!(),
+
+    // slots
+    load: qt_method!(
+        fn load(&mut self, inputs_dir: QString) {
+            self.load_impl(&inputs_dir.to_string());
+            self.update_highlights();
+            self.selected_matches = QVariantList::default();
+            self.grid_changed();
+            self.selected_matches_changed();
+            self.error_msg_changed();
+        }
+    ),
+    filter_names: qt_method!(
+        fn filter_names(&self) -> QVariantList {
+            WordMatch::filters()
+                .into_iter()
+                .map(QString::from)
+                .collect()
+        }
+    ),
+    select_cell: qt_method!(
+        fn select_cell(&mut self, x: i32, y: i32) {
+            self.selected_matches = self
+                .filtered_matches()
+                .filter(|word_match| word_match.cells().contains(&(x, y)))
+                .map(|word_match| QString::from(word_match.describe()))
+                .collect();
+            self.selected_matches_changed();
+        }
+    ),
+}
+
+impl WordSearchView {
+    fn set_filter(&mut self, filter: QString) {
+        self.filter = filter;
+        self.update_highlights();
+        self.filter_changed();
+    }
+
+    fn load_impl(&mut self, inputs_dir: &str) {
+        match get_input(4, inputs_dir).and_then(|input| find_matches(&input)) {
+            Err(msg) => {
+                self.found = None;
+                self.characters = QString::default();
+                self.grid_width = 0;
+                self.grid_height = 0;
+                self.error_msg = QString::from(msg);
+            }
+            Ok(found) => {
+                self.characters = QString::from(found.characters.as_str());
+                self.grid_width = found.width as i32;
+                self.grid_height = found.height as i32;
+                self.found = Some(found);
+                self.error_msg = QString::default();
+            }
+        }
+    }
+
+    fn filtered_matches(&self) -> impl Iterator<Item = &WordMatch> {
+        let filter = match self.filter.to_string() {
+            filter if filter.is_empty() => String::from("all"),
+            filter => filter,
+        };
+
+        self.found
+            .iter()
+            .flat_map(|found| found.matches.iter())
+            .filter(move |word_match| word_match.passes_filter(&filter))
+    }
+
+    /// Counts, for every cell, how many of the filtered matches pass through it.
+    fn update_highlights(&mut self) {
+        let width = self.grid_width;
+        let mut highlights = vec![0; (self.grid_width * self.grid_height) as usize];
+        let mut match_count = 0;
+
+        for word_match in self.filtered_matches() {
+            for (x, y) in word_match.cells() {
+                highlights[(y * width + x) as usize] += 1;
+            }
+            match_count += 1;
+        }
+
+        self.highlights = highlights.into_iter().collect();
+        self.match_count = match_count;
+        self.highlights_changed();
+    }
+}
+
+#[derive(QObject, Default)]
+pub struct CorruptedMemoryView {
+    // fields
+    base: qt_base_class!(trait QObject),
+    tokens: Vec<ExecutedToken>,
+    html: qt_property!(QString; NOTIFY memory_changed),
+    mul_count: qt_property!(i32; NOTIFY memory_changed),
+    enabled_mul_count: qt_property!(i32; NOTIFY memory_changed),
+    error_msg: qt_property!(QString; NOTIFY memory_changed),
+
+    // signals
+    memory_changed: qt_signal!(),
+
+    // slots
+    load: qt_method!(
+        fn load(&mut self, inputs_dir: QString) {
+            self.load_impl(&inputs_dir.to_string());
+            self.memory_changed();
+        }
+    ),
+    describe_token: qt_method!(
+        fn describe_token(&self, index: i32) -> QString {
+            let executed = match self.tokens.get(index as usize) {
+                Some(executed) => executed,
+                None => return QString::default(),
+            };
+
+            let description = match executed.token.kind {
+                TokenKind::Do => String::from("do() enables following instructions"),
+                TokenKind::Dont => String::from("don't() disables following instructions"),
+                TokenKind::Mul(num1, num2) => format!(
+                    "mul({num1},{num2}) = {}{}",
+                    num1 * num2,
+                    if executed.enabled { "" } else { " (disabled)" }
+                ),
+            };
+
+            QString::from(format!(
+                "{description}\nRunning sum: {}\nRunning sum of enabled: {}",
+                executed.running_sum, executed.enabled_running_sum
+            ))
+        }
+    ),
+}
+
+impl CorruptedMemoryView {
+    fn load_impl(&mut self, inputs_dir: &str) {
+        match get_input(3, inputs_dir) {
+            Err(msg) => {
+                self.tokens.clear();
+                self.html = QString::default();
+                self.mul_count = 0;
+                self.enabled_mul_count = 0;
+                self.error_msg = QString::from(msg);
+            }
+            Ok(input) => {
+                self.tokens = execute(&input);
+                self.html = QString::from(self.to_html(&input));
+                self.mul_count = self.muls().count() as i32;
+                self.enabled_mul_count = self.muls().filter(|mul| mul.enabled).count() as i32;
+                self.error_msg = QString::default();
+            }
+        }
+    }
+
+    fn muls(&self) -> impl Iterator<Item = &ExecutedToken> {
+        self.tokens
+            .iter()
+            .filter(|executed| matches!(executed.token.kind, TokenKind::Mul(..)))
+    }
+
+    /// Renders the raw input with every token wrapped in a link to its index, so hovering
+    /// it in a rich text view can look the token up.
+    fn to_html(&self, input: &str) -> String {
+        let mut html = String::with_capacity(input.len() * 2);
+        let mut position = 0;
+
+        for (index, executed) in self.tokens.iter().enumerate() {
+            let token = executed.token;
+            html.push_str(&escape_html(&input[position..token.start]));
+
+            let style = match token.kind {
+                TokenKind::Do | TokenKind::Dont => "color:#1565c0; text-decoration:none",
+                TokenKind::Mul(..) if executed.enabled => "color:#2e7d32; text-decoration:none",
+                TokenKind::Mul(..) => "color:#bdbdbd; text-decoration:line-through",
+            };
+            html.push_str(&format!(
+                "<a href=\"{index}\" style=\"{style}\"><b>{}</b></a>",
+                escape_html(&input[token.start..token.end])
+            ));
+            position = token.end;
+        }
+        html.push_str(&escape_html(&input[position..]));
+
+        format!("<pre style=\"color:#757575\">{html}</pre>")
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+const HISTOGRAM_BINS: usize = 40;
+const TOP_CONTRIBUTORS: usize = 10;
+
+#[derive(QObject, Default)]
+pub struct LocationListsView {
+    // fields
+    base: qt_base_class!(trait QObject),
+    left_histogram: qt_property!(QVariantList; NOTIFY breakdown_changed),
+    right_histogram: qt_property!(QVariantList; NOTIFY breakdown_changed),
+    histogram_min: qt_property!(i32; NOTIFY breakdown_changed),
+    histogram_max: qt_property!(i32; NOTIFY breakdown_changed),
+    top_distances: qt_property!(QVariantList; NOTIFY breakdown_changed),
+    top_similarities: qt_property!(QVariantList; NOTIFY breakdown_changed),
+    error_msg: qt_property!(QString; NOTIFY breakdown_changed),
+
+    // signals
+    breakdown_changed: qt_signal!(),
+
+    // slots
+    load: qt_method!(
+        fn load(&mut self, inputs_dir: QString) {
+            match get_input(1, &inputs_dir.to_string()).and_then(breakdown) {
+                Err(msg) => {
+                    self.left_histogram = QVariantList::default();
+                    self.right_histogram = QVariantList::default();
+                    self.top_distances = QVariantList::default();
+                    self.top_similarities = QVariantList::default();
+                    self.error_msg = QString::from(msg);
+                }
+                Ok(breakdown) => {
+                    self.show(&breakdown);
+                    self.error_msg = QString::default();
+                }
+            }
+            self.breakdown_changed();
+        }
+    ),
+}
+
+impl LocationListsView {
+    fn show(&mut self, breakdown: &Breakdown) {
+        let all_values = breakdown.columns.iter().flatten();
+        let min = all_values.clone().copied().min().unwrap_or_default();
+        let max = all_values.copied().max().unwrap_or_default();
+
+        self.histogram_min = min;
+        self.histogram_max = max;
+        self.left_histogram = histogram(&breakdown.columns[0], min, max);
+        self.right_histogram = histogram(&breakdown.columns[1], min, max);
+
+        let mut pairs = breakdown.pairs.clone();
+        pairs.sort_by_key(|pair| std::cmp::Reverse(pair.distance));
+        self.top_distances = pairs
+            .iter()
+            .take(TOP_CONTRIBUTORS)
+            .map(|pair| {
+                QString::from(format!(
+                    "|{} - {}| = {}",
+                    pair.left, pair.right, pair.distance
+                ))
+            })
+            .collect();
+
+        let mut occurrences = breakdown.occurrences.clone();
+        occurrences.sort_by_key(|occurrence| std::cmp::Reverse(occurrence.similarity));
+        self.top_similarities = occurrences
+            .iter()
+            .take_while(|occurrence| occurrence.similarity > 0)
+            .take(TOP_CONTRIBUTORS)
+            .map(|occurrence| {
+                QString::from(format!(
+                    "{} \u{d7} {} in right \u{d7} {} in left = {}",
+                    occurrence.value,
+                    occurrence.right_count,
+                    occurrence.left_count,
+                    occurrence.similarity
+                ))
+            })
+            .collect();
+    }
+}
+
+/// Splits `min..=max` into equally wide bins and counts the values falling into each.
+fn histogram(values: &[i32], min: i32, max: i32) -> QVariantList {
+    let mut bins = vec![0; HISTOGRAM_BINS];
+    let width = (max as i64 - min as i64 + 1) as f64 / HISTOGRAM_BINS as f64;
+    for v in values.iter() {
+        let bin = ((*v as i64 - min as i64) as f64 / width) as usize;
+        bins[bin.min(HISTOGRAM_BINS - 1)] += 1;
+    }
+
+    bins.into_iter().collect()
+}
+
+#[derive(SimpleListItem, Default, Clone)]
+pub struct ReportItem {
+    pub number: i32,
+    pub levels: QVariantList,
+    pub diagnostic: QString,
+    pub category: QString,
+    pub level_index: i32,
+}
+
+#[derive(QObject, Default)]
+pub struct ReportsView {
+    // fields
+    base: qt_base_class!(trait QObject),
+    diagnosed: Vec<(Vec<i32>, Diagnostic)>,
+    filter: qt_property!(QString; WRITE set_filter NOTIFY filter_changed),
+    reports: qt_property!(RefCell<SimpleListModel<ReportItem>>; CONST),
+    report_count: qt_property!(i32; NOTIFY reports_changed),
+    error_msg: qt_property!(QString; NOTIFY error_msg_changed),
+
+    // signals
+    filter_changed: qt_signal!(),
+    reports_changed: qt_signal!(),
+    error_msg_changed: qt_signal!(),
+
+    // slots
+    load: qt_method!(
+        fn load(&mut self, inputs_dir: QString) {
+            match get_input(2, &inputs_dir.to_string()).and_then(diagnose_reports) {
+                Err(msg) => {
+                    self.diagnosed.clear();
+                    self.error_msg = QString::from(msg);
+                }
+                Ok(diagnosed) => {
+                    self.diagnosed = diagnosed;
+                    self.error_msg = QString::default();
+                }
+            }
+            self.update_reports();
+            self.error_msg_changed();
+        }
+    ),
+    filter_names: qt_method!(
+        fn filter_names(&self) -> QVariantList {
+            std::iter::once("all")
+                .chain(Diagnostic::CATEGORIES)
+                .map(QString::from)
+                .collect()
+        }
+    ),
+}
+
+impl ReportsView {
+    fn set_filter(&mut self, filter: QString) {
+        self.filter = filter;
+        self.update_reports();
+        self.filter_changed();
+    }
+
+    fn update_reports(&mut self) {
+        let filter = self.filter.to_string();
+        let items: Vec<ReportItem> = self
+            .diagnosed
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, diagnostic))| {
+                filter.is_empty() || filter == "all" || filter == diagnostic.category()
+            })
+            .map(|(i, (levels, diagnostic))| ReportItem {
+                number: i as i32 + 1,
+                levels: levels.iter().copied().collect(),
+                diagnostic: QString::from(diagnostic.to_string()),
+                category: QString::from(diagnostic.category()),
+                level_index: diagnostic.level_index().map_or(-1, |index| index as i32),
+            })
+            .collect();
+
+        self.report_count = items.len() as i32;
+        self.reports.borrow_mut().reset_data(items);
+        self.reports_changed();
+    }
+}
+
+#[derive(QObject, Default)]
+pub struct FramePlayer {
+    // fields
+    base: qt_base_class!(trait QObject),
+    recording: Recording,
+    frame_count: qt_property!(i32; NOTIFY recording_changed),
+    grid_width: qt_property!(i32; NOTIFY recording_changed),
+    grid_height: qt_property!(i32; NOTIFY recording_changed),
+    current: qt_property!(i32; WRITE set_current NOTIFY frame_changed),
+    cells: qt_property!(QString; NOTIFY frame_changed),
+    label: qt_property!(QString; NOTIFY frame_changed),
+    error_msg: qt_property!(QString; NOTIFY recording_changed),
+
+    // signals
+    recording_changed: qt_signal!(),
+    frame_changed: qt_signal!(),
+
+    // slots
+    load: qt_method!(
+        fn load(&mut self, day_number: i32, inputs_dir: QString) {
+            let recording = get_recorder(day_number)
+                .and_then(|recorder| recorder(get_input(day_number, &inputs_dir.to_string())?));
+            match recording {
+                Err(msg) => {
+                    self.recording = Recording::default();
+                    self.error_msg = QString::from(msg);
+                }
+                Ok(recording) => {
+                    self.recording = recording;
+                    self.error_msg = QString::default();
+                }
+            }
+
+            let first = self.recording.frames().first();
+            self.frame_count = self.recording.frames().len() as i32;
+            self.grid_width = first.map_or(0, |frame| frame.width as i32);
+            self.grid_height = first.map_or(0, |frame| frame.height as i32);
+            self.recording_changed();
+            self.set_current(0);
+        }
+    ),
+    recorded_days: qt_method!(
+        fn recorded_days(&self) -> QVariantList {
+            DAYS.iter()
+                .filter(|day| day.recorder.is_some())
+                .map(|day| day.number)
+                .collect()
+        }
+    ),
+}
+
+impl FramePlayer {
+    fn set_current(&mut self, current: i32) {
+        let last = self.frame_count - 1;
+        self.current = current.min(last).max(0);
+
+        match self.recording.frames().get(self.current as usize) {
+            Some(frame) => {
+                self.cells = QString::from(frame.cells.iter().collect::<String>());
+                self.label = QString::from(frame.label.as_str());
+            }
+            None => {
+                self.cells = QString::default();
+                self.label = QString::default();
+            }
         }
+        self.frame_changed();
     }
 }
 
 pub fn register_all_qml_types() {
     qml_register_type::<AOC24Solutions>(cstr!("AOCSolutions"), 1, 0, cstr!("AOCSolutions"));
+    qml_register_type::<WordSearchView>(cstr!("AOCSolutions"), 1, 0, cstr!("WordSearchView"));
+    qml_register_type::<LocationListsView>(cstr!("AOCSolutions"), 1, 0, cstr!("LocationListsView"));
+    qml_register_type::<ReportsView>(cstr!("AOCSolutions"), 1, 0, cstr!("ReportsView"));
+    qml_register_type::<FramePlayer>(cstr!("AOCSolutions"), 1, 0, cstr!("FramePlayer"));
+    qml_register_type::<CorruptedMemoryView>(
+        cstr!("AOCSolutions"),
+        1,
+        0,
+        cstr!("CorruptedMemoryView"),
+    );
 }
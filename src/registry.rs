@@ -0,0 +1,100 @@
+use crate::frames::Recording;
+use crate::utils::Puzzle;
+use crate::{day1, day2, day20, day21, day22, day23, day24, day25, day3, day4};
+
+pub type Parser = fn(String) -> Result<Box<dyn Puzzle>, String>;
+pub type Recorder = fn(String) -> Result<Recording, String>;
+
+pub struct Day {
+    pub number: i32,
+    pub title: &'static str,
+    pub parser: Option<Parser>,
+    pub recorder: Option<Recorder>,
+}
+
+const fn day(number: i32, title: &'static str, parser: Option<Parser>) -> Day {
+    Day {
+        number,
+        title,
+        parser,
+        recorder: None,
+    }
+}
+
+impl Day {
+    const fn with_recorder(self, recorder: Recorder) -> Day {
+        Day {
+            recorder: Some(recorder),
+            ..self
+        }
+    }
+}
+
+pub const DAYS: [Day; 25] = [
+    day(1, "Historian Hysteria", Some(day1::parse)),
+    day(2, "Red-Nosed Reports", Some(day2::parse)),
+    day(3, "Mull It Over", Some(day3::parse)),
+    day(4, "Ceres Search", Some(day4::parse)),
+    day(5, "Print Queue", None),
+    day(6, "Guard Gallivant", None),
+    day(7, "Bridge Repair", None),
+    day(8, "Resonant Collinearity", None),
+    day(9, "Disk Fragmenter", None),
+    day(10, "Hoof It", None),
+    day(11, "Plutonian Pebbles", None),
+    day(12, "Garden Groups", None),
+    day(13, "Claw Contraption", None),
+    day(14, "Restroom Redoubt", None),
+    day(15, "Warehouse Woes", None),
+    day(16, "Reindeer Maze", None),
+    day(17, "Chronospatial Computer", None),
+    day(18, "RAM Run", None),
+    day(19, "Linen Layout", None),
+    day(20, "Race Condition", Some(day20::parse)).with_recorder(day20::record),
+    day(21, "Keypad Conundrum", Some(day21::parse)),
+    day(22, "Monkey Market", Some(day22::parse)),
+    day(23, "LAN Party", Some(day23::parse)),
+    day(24, "Crossed Wires", Some(day24::parse)),
+    day(25, "Code Chronicle", Some(day25::parse)),
+];
+
+pub fn get_day(day_number: i32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == day_number)
+}
+
+pub fn get_parser(day_number: i32) -> Result<Parser, String> {
+    get_day(day_number)
+        .and_then(|day| day.parser)
+        .ok_or(String::from("solution for given day not implemented"))
+}
+
+pub fn get_recorder(day_number: i32) -> Result<Recorder, String> {
+    get_day(day_number)
+        .and_then(|day| day.recorder)
+        .ok_or(String::from("given day has no recorded simulation"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    #[test]
+    pub fn days_are_in_order() {
+        for (i, day) in registry::DAYS.iter().enumerate() {
+            assert_eq!(day.number, i as i32 + 1);
+        }
+    }
+
+    #[test]
+    pub fn get_parser() {
+        assert!(registry::get_parser(1).is_ok());
+        assert!(registry::get_parser(5).is_err());
+        assert!(registry::get_parser(26).is_err());
+    }
+
+    #[test]
+    pub fn get_recorder() {
+        assert!(registry::get_recorder(20).is_ok());
+        assert!(registry::get_recorder(1).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use crate::frames::{Frame, Recording};
+
+/// Colors of the cells by their character, the first color is used for unlisted characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<[u8; 3]>,
+    characters: Vec<char>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::parse("*=ffa000,.=ffffff,#=424242,@=d32f2f").expect("default palette is valid")
+    }
+}
+
+impl Palette {
+    /// Parses comma separated `<char>=<rrggbb>` entries, `*` sets the color of unlisted characters.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut palette = Self {
+            colors: vec![[0, 0, 0]],
+            characters: Vec::new(),
+        };
+        palette.extend(spec)?;
+        Ok(palette)
+    }
+
+    /// Adds or overrides colors with entries in the format of [`Palette::parse`].
+    pub fn extend(&mut self, spec: &str) -> Result<(), String> {
+        for entry in spec.split(',') {
+            let invalid = || format!("invalid palette entry \"{entry}\", expected <char>=<rrggbb>");
+            let (character, color) = entry.split_once('=').ok_or_else(invalid)?;
+            let mut characters = character.chars();
+            let character = match (characters.next(), characters.next()) {
+                (Some(character), None) => character,
+                _ => return Err(invalid()),
+            };
+            let color = u32::from_str_radix(color.trim_start_matches('#'), 16)
+                .ok()
+                .filter(|_| color.trim_start_matches('#').len() == 6)
+                .ok_or_else(invalid)?;
+            let [_, r, g, b] = color.to_be_bytes();
+
+            if character == '*' {
+                self.colors[0] = [r, g, b];
+            } else if let Some(i) = self.characters.iter().position(|c| *c == character) {
+                self.colors[i + 1] = [r, g, b];
+            } else if self.colors.len() < 256 {
+                self.characters.push(character);
+                self.colors.push([r, g, b]);
+            } else {
+                return Err("palette has more than 256 colors".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn index(&self, character: char) -> u8 {
+        self.characters
+            .iter()
+            .position(|c| *c == character)
+            .map_or(0, |i| i as u8 + 1)
+    }
+
+    fn rgb(&self) -> Vec<u8> {
+        self.colors.iter().flatten().copied().collect()
+    }
+}
+
+/// Scales the frame up so every cell becomes a square of palette indices.
+fn rasterize(frame: &Frame, palette: &Palette, cell_size: usize) -> Vec<u8> {
+    let row_length = frame.width * cell_size;
+    let mut pixels = Vec::with_capacity(row_length * frame.height * cell_size);
+
+    for row in frame.cells.chunks(frame.width) {
+        let pixel_row: Vec<u8> = row
+            .iter()
+            .flat_map(|c| std::iter::repeat_n(palette.index(*c), cell_size))
+            .collect();
+        for _ in 0..cell_size {
+            pixels.extend_from_slice(&pixel_row);
+        }
+    }
+
+    pixels
+}
+
+fn image_size(recording: &Recording, cell_size: usize) -> Result<(usize, usize), String> {
+    let frame = recording
+        .frames()
+        .first()
+        .ok_or("recording has no frames".to_string())?;
+    if cell_size == 0 {
+        return Err("cell size must be positive".to_string());
+    }
+
+    Ok((frame.width * cell_size, frame.height * cell_size))
+}
+
+/// Writes every frame into `output_dir` as `frameNNNN.png` and returns the written paths.
+pub fn write_png_sequence(
+    recording: &Recording,
+    palette: &Palette,
+    cell_size: usize,
+    output_dir: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let (width, height) = image_size(recording, cell_size)?;
+    std::fs::create_dir_all(output_dir).map_err(|e| e.to_string())?;
+
+    let mut paths = Vec::new();
+    for (i, frame) in recording.frames().iter().enumerate() {
+        let path = output_dir.join(format!("frame{i:04}.png"));
+        let file = File::create(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+        let mut encoder = png::Encoder::new(BufWriter::new(file), width as u32, height as u32);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(palette.rgb());
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&rasterize(frame, palette, cell_size)))
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+/// Writes all frames into a looping animated GIF, `delay` is given in hundredths of a second.
+pub fn write_gif(
+    recording: &Recording,
+    palette: &Palette,
+    cell_size: usize,
+    delay: u16,
+    path: &Path,
+) -> Result<(), String> {
+    let (width, height) = image_size(recording, cell_size)?;
+    let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => (width, height),
+        _ => return Err("animation is too large for a GIF".to_string()),
+    };
+    let file = File::create(path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    let mut encoder = gif::Encoder::new(BufWriter::new(file), width, height, &palette.rgb())
+        .map_err(|e| e.to_string())?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(|e| e.to_string())?;
+
+    for frame in recording.frames() {
+        let mut gif_frame = gif::Frame::from_indexed_pixels(
+            width,
+            height,
+            rasterize(frame, palette, cell_size),
+            None,
+        );
+        gif_frame.delay = delay;
+        encoder.write_frame(&gif_frame).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::frames::Recording;
+
+    use super::{rasterize, write_gif, write_png_sequence, Palette};
+
+    fn recording() -> Recording {
+        let mut recording = Recording::new();
+        recording
+            .push("first", ["#.", ".@"].iter().map(|row| row.chars()))
+            .expect("expected Ok(), got Err()");
+        recording
+            .push("second", ["#@", ".x"].iter().map(|row| row.chars()))
+            .expect("expected Ok(), got Err()");
+        recording
+    }
+
+    #[test]
+    pub fn parse_palette() {
+        let palette = Palette::parse("#=000000,.=ffffff,*=ff0000").expect("expected Ok(Palette)");
+        assert_eq!(palette.index('#'), 1);
+        assert_eq!(palette.index('.'), 2);
+        assert_eq!(palette.index('x'), 0);
+        assert_eq!(palette.rgb(), vec![255, 0, 0, 0, 0, 0, 255, 255, 255]);
+
+        Palette::parse("#=00000").expect_err("expected Err(), got Ok(Palette)");
+        Palette::parse("ab=000000").expect_err("expected Err(), got Ok(Palette)");
+        Palette::parse("#000000").expect_err("expected Err(), got Ok(Palette)");
+
+        let mut palette = Palette::default();
+        palette
+            .extend("#=123456,x=abcdef")
+            .expect("expected Ok(), got Err()");
+        assert_eq!(palette.index('#'), 2);
+        assert_eq!(palette.index('x'), 4);
+        assert_eq!(&palette.rgb()[6..9], &[0x12, 0x34, 0x56]);
+    }
+
+    #[test]
+    pub fn rasterize_frame() {
+        let palette = Palette::parse("#=000000,@=ffffff").expect("expected Ok(Palette)");
+        let pixels = rasterize(&recording().frames()[0], &palette, 2);
+
+        assert_eq!(pixels, vec![1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 2, 2, 0, 0, 2, 2]);
+    }
+
+    #[test]
+    pub fn write_files() {
+        let output_dir = std::env::temp_dir().join(format!("aoc24-render-{}", std::process::id()));
+        let palette = Palette::default();
+
+        let paths = write_png_sequence(&recording(), &palette, 3, &output_dir)
+            .expect("expected Ok(paths), got Err()");
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().all(|path| path.is_file()));
+
+        let gif_path = output_dir.join("animation.gif");
+        write_gif(&recording(), &palette, 3, 10, &gif_path).expect("expected Ok(), got Err()");
+        let header = std::fs::read(&gif_path).expect("cannot read GIF");
+        assert!(header.starts_with(b"GIF89a"));
+
+        std::fs::remove_dir_all(&output_dir).expect("cannot clean up");
+    }
+}
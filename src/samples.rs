@@ -0,0 +1,342 @@
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_SAMPLES_DIR: &str = "resources/samples";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub input: String,
+    pub one_star_answer: Option<String>,
+    pub two_star_answer: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Element {
+    Example { text: String, introduced: bool },
+    Answer(String),
+}
+
+/// Finds the samples in a saved puzzle description page.
+///
+/// Every `<article>` describes one part and its answer is the last emphasized `<code>` in it.
+/// The part's sample is the first example given before that answer which the text introduces
+/// with "for example"; the other examples only illustrate it. A second part without such an
+/// example reuses the first part's sample, and a part without a previous sample falls back to
+/// its longest example, the first one among equally long.
+pub fn extract_samples(html: &str) -> Result<Vec<Sample>, String> {
+    let mut articles: Vec<&str> = html
+        .split("<article")
+        .skip(1)
+        .filter_map(|article| article.split_once("</article>").map(|(article, _)| article))
+        .collect();
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    let mut samples: Vec<Sample> = Vec::new();
+    for (part, article) in articles.iter().take(2).enumerate() {
+        let elements = parse_elements(article);
+        let answer = elements
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(i, element)| match element {
+                Element::Answer(answer) => Some((i, answer)),
+                Element::Example { .. } => None,
+            });
+        let (answer_index, answer) = match answer {
+            Some(answer) => answer,
+            None => continue,
+        };
+
+        let examples: Vec<(&String, bool)> = elements[..answer_index]
+            .iter()
+            .filter_map(|element| match element {
+                Element::Example { text, introduced } => Some((text, *introduced)),
+                Element::Answer(_) => None,
+            })
+            .collect();
+        let introduced = examples
+            .iter()
+            .find(|(_, introduced)| *introduced)
+            .map(|(text, _)| *text);
+        let example = match (introduced, samples.last()) {
+            (Some(example), _) => Some(example),
+            (None, Some(_)) => None,
+            (None, None) => examples
+                .iter()
+                .rev()
+                .max_by_key(|(text, _)| text.lines().count())
+                .map(|(text, _)| *text),
+        };
+
+        match (example, part) {
+            (Some(example), 0) => samples.push(Sample {
+                input: example.clone(),
+                one_star_answer: Some(answer.clone()),
+                two_star_answer: None,
+            }),
+            (Some(example), _) => samples.push(Sample {
+                input: example.clone(),
+                one_star_answer: None,
+                two_star_answer: Some(answer.clone()),
+            }),
+            (None, 0) => {}
+            (None, _) => {
+                if let Some(previous) = samples.last_mut() {
+                    previous.two_star_answer = Some(answer.clone());
+                }
+            }
+        }
+    }
+
+    if samples.is_empty() {
+        return Err(String::from("no example with an emphasized answer found"));
+    }
+    Ok(samples)
+}
+
+pub fn write_samples(
+    day_number: i32,
+    samples: &[Sample],
+    samples_dir: &str,
+) -> Result<Vec<PathBuf>, String> {
+    let answers_dir = Path::new(samples_dir).join("answers");
+    std::fs::create_dir_all(&answers_dir).map_err(|err| format!("{err}. \"{samples_dir}\""))?;
+    let mut paths = Vec::new();
+
+    for (i, sample) in samples.iter().enumerate() {
+        let file_name = format!("day{day_number}-{}.txt", i + 1);
+        let mut answers = String::new();
+        if let Some(answer) = &sample.one_star_answer {
+            answers.push_str(&format!("one_star = {answer}\n"));
+        }
+        if let Some(answer) = &sample.two_star_answer {
+            answers.push_str(&format!("two_star = {answer}\n"));
+        }
+
+        let path = Path::new(samples_dir).join(&file_name);
+        let answers_path = answers_dir.join(&file_name);
+        std::fs::write(&path, &sample.input)
+            .map_err(|err| format!("{err}. \"{}\"", path.display()))?;
+        std::fs::write(&answers_path, answers)
+            .map_err(|err| format!("{err}. \"{}\"", answers_path.display()))?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+fn parse_elements(html: &str) -> Vec<Element> {
+    const EXAMPLE: (&str, &str) = ("<pre><code>", "</code></pre>");
+    const ANSWER: (&str, &str) = ("<code><em>", "</em></code>");
+    let mut elements = Vec::new();
+    let mut rest = html;
+
+    loop {
+        let example_start = rest.find(EXAMPLE.0);
+        let answer_start = rest.find(ANSWER.0);
+        let ((start, end), position) = match (example_start, answer_start) {
+            (Some(example), Some(answer)) if answer < example => (ANSWER, answer),
+            (Some(example), _) => (EXAMPLE, example),
+            (None, Some(answer)) => (ANSWER, answer),
+            (None, None) => break,
+        };
+
+        let content = &rest[position + start.len()..];
+        let length = match content.find(end) {
+            Some(length) => length,
+            None => break,
+        };
+        let text = decode_entities(&strip_tags(&content[..length]));
+        elements.push(match start {
+            "<pre><code>" => Element::Example {
+                text,
+                introduced: strip_tags(&rest[..position])
+                    .to_lowercase()
+                    .contains("for example"),
+            },
+            _ => Element::Answer(text),
+        });
+        rest = &content[length + end.len()..];
+    }
+    elements
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_samples, write_samples, Sample};
+
+    fn day3_page() -> String {
+        String::from(
+            r#"<main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<p>For example, consider the following section of corrupted memory:</p>
+<pre><code>x<em>mul(2,4)</em>%&amp;mul[3,7]!@^do_not_<em>mul(5,5)</em>+mul(32,64]then(<em>mul(11,8)mul(8,5)</em>)
+</code></pre>
+<p>Adding up the result of each instruction produces <code><em>161</em></code> (<code>2*4 + 5*5 + 11*8 + 8*5</code>).</p>
+</article>
+<p>Your puzzle answer was <code>178538786</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>For example:</p>
+<pre><code>x<em>mul(2,4)</em>&amp;mul[3,7]!^<em>don't()</em>_mul(5,5)+mul(32,64](mul(11,8)un<em>do()</em>?<em>mul(8,5)</em>)
+</code></pre>
+<p>This time, the sum of the results is <code><em>48</em></code> (<code>2*4 + 8*5</code>).</p>
+</article>
+</main>"#,
+        )
+    }
+
+    fn day4_page() -> String {
+        String::from(
+            r#"<main>
+<article class="day-desc"><h2>--- Day 4: Ceres Search ---</h2><p>"Looks like the Chief's not here. Next!" One of The Historians pulls out a device and pushes the only button on it. After a brief flash, you recognize the interior of the <a href="/2019/day/10">Ceres monitoring station</a>!</p>
+<p>As the search for the Chief continues, a small Elf who lives on the station tugs on your shirt; she'd like to know if you could help her with her <em>word search</em> (your puzzle input). She only has to find one word: <code>XMAS</code>.</p>
+<p>This word search allows words to be horizontal, vertical, diagonal, written backwards, or even overlapping other words. It's a little unusual, though, as you don't merely need to find one instance of <code>XMAS</code> - you need to find <em>all of them</em>. Here are a few ways <code>XMAS</code> might appear, where irrelevant characters have been replaced with <code>.</code>:</p>
+<pre><code>..X...
+.SAMX.
+.A..A.
+XMAS.S
+.X....
+</code></pre>
+<p>The actual word search will be full of letters instead. For example:</p>
+<pre><code>MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+</code></pre>
+<p>In this word search, <code>XMAS</code> occurs a total of <code><em>18</em></code> times; here's the same word search again, but where letters not involved in any <code>XMAS</code> have been replaced with <code>.</code>:</p>
+<pre><code>....XXMAS.
+.SAMXMS...
+...S..A...
+..A.A.MS.X
+XMASAMX.MM
+X.....XA.A
+S.S.S.S.SS
+.A.A.A.A.A
+..M.M.M.MM
+.X.X.XMASX
+</code></pre>
+<p>Take a look at the little Elf's word search. <em>How many times does <code>XMAS</code> appear?</em></p>
+</article>
+<p>Your puzzle answer was <code>2547</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>The Elf looks quizzically at you. Did you misunderstand the assignment?</p>
+<p>Looking for the instructions, you flip over the word search to find that this isn't actually an <code><em>XMAS</em></code> puzzle; it's an <span title="This part originally involved searching for something else, but this joke was too dumb to pass up."><code><em>X-MAS</em></code></span> puzzle in which you're supposed to find two <code>MAS</code> in the shape of an <code>X</code>. One way to achieve that is like this:</p>
+<pre><code>M.S
+.A.
+M.S
+</code></pre>
+<p>Irrelevant characters have again been replaced with <code>.</code> in the above diagram. Within the <code>X</code>, each <code>MAS</code> can be written forwards or backwards.</p>
+<p>Here's the same example from before, but this time all of the <code>X-MAS</code>es have been kept instead:</p>
+<pre><code>.M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........
+</code></pre>
+<p>In this example, an <code>X-MAS</code> appears <code><em>9</em></code> times.</p>
+<p>Flip the word search from the instructions back over to the word search side and try again. <em>How many times does an <code>X-MAS</code> appear?</em></p>
+</article>
+<p>Your puzzle answer was <code>1939</code>.</p>
+</main>"#,
+        )
+    }
+
+    #[test]
+    pub fn separate_examples() {
+        let samples = extract_samples(&day3_page()).expect("expected Ok(samples), got Err()");
+
+        assert_eq!(
+            samples,
+            vec![
+                Sample {
+                    input: String::from(
+                        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))\n"
+                    ),
+                    one_star_answer: Some(String::from("161")),
+                    two_star_answer: None,
+                },
+                Sample {
+                    input: String::from(
+                        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n"
+                    ),
+                    one_star_answer: None,
+                    two_star_answer: Some(String::from("48")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    pub fn shared_example() {
+        let samples = extract_samples(&day4_page()).expect("expected Ok(samples), got Err()");
+
+        assert_eq!(
+            samples,
+            vec![Sample {
+                input: String::from(
+                    "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\n\
+                     XXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX\n"
+                ),
+                one_star_answer: Some(String::from("18")),
+                two_star_answer: Some(String::from("9")),
+            }]
+        );
+        assert!(extract_samples("<article><p>No examples.</p></article>").is_err());
+    }
+
+    #[test]
+    pub fn write_files() {
+        let dir = std::env::temp_dir().join(format!("aoc24-samples-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let dir = dir.to_str().unwrap();
+        let samples = extract_samples(&day3_page()).expect("expected Ok(samples), got Err()");
+
+        let paths = write_samples(3, &samples, dir).expect("expected Ok(paths), got Err()");
+        assert_eq!(paths.len(), 2);
+        assert_eq!(
+            std::fs::read_to_string(&paths[1]).unwrap(),
+            samples[1].input
+        );
+        assert_eq!(
+            std::fs::read_to_string(format!("{dir}/answers/day3-1.txt")).unwrap(),
+            "one_star = 161\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(format!("{dir}/answers/day3-2.txt")).unwrap(),
+            "two_star = 48\n"
+        );
+        std::fs::remove_dir_all(dir).expect("cannot clean up");
+    }
+}
@@ -0,0 +1,145 @@
+use std::path::{Path, PathBuf};
+
+const DEFAULT_INPUTS_DIR: &str = "resources/inputs";
+
+/// GUI state persisted between launches in `$XDG_CONFIG_HOME/aoc24/settings.conf`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub inputs_dir: String,
+    /// Window position and size, negative when never saved.
+    pub window_x: i32,
+    pub window_y: i32,
+    pub window_width: i32,
+    pub window_height: i32,
+    /// Zero when no day was selected yet.
+    pub last_day: i32,
+    pub theme: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        let inputs_dir = std::fs::canonicalize(DEFAULT_INPUTS_DIR)
+            .ok()
+            .filter(|path| path.is_dir())
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+
+        Self {
+            inputs_dir,
+            window_x: -1,
+            window_y: -1,
+            window_width: -1,
+            window_height: -1,
+            last_day: 0,
+            theme: String::from("light"),
+        }
+    }
+}
+
+impl Settings {
+    /// Reads the settings file, falling back to defaults for it or any of its missing entries.
+    pub fn load() -> Self {
+        config_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .map(|text| Self::parse(&text))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = config_path().ok_or("cannot determine config directory".to_string())?;
+        let write = |path: &Path| {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, self.to_text())
+        };
+        write(&path).map_err(|err| format!("{err}. \"{}\"", path.display()))
+    }
+
+    /// Parses `key = value` lines, ignoring unknown keys and invalid values.
+    fn parse(text: &str) -> Self {
+        let mut settings = Self::default();
+
+        for line in text.lines() {
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => continue,
+            };
+            let number = value.parse::<i32>();
+
+            match (key, number) {
+                ("inputs_dir", _) if !value.is_empty() => settings.inputs_dir = value.to_string(),
+                ("theme", _) if !value.is_empty() => settings.theme = value.to_string(),
+                ("window_x", Ok(number)) => settings.window_x = number,
+                ("window_y", Ok(number)) => settings.window_y = number,
+                ("window_width", Ok(number)) => settings.window_width = number,
+                ("window_height", Ok(number)) => settings.window_height = number,
+                ("last_day", Ok(number)) => settings.last_day = number,
+                _ => {}
+            }
+        }
+
+        settings
+    }
+
+    fn to_text(&self) -> String {
+        format!(
+            "inputs_dir = {}\n\
+             window_x = {}\n\
+             window_y = {}\n\
+             window_width = {}\n\
+             window_height = {}\n\
+             last_day = {}\n\
+             theme = {}\n",
+            self.inputs_dir,
+            self.window_x,
+            self.window_y,
+            self.window_width,
+            self.window_height,
+            self.last_day,
+            self.theme
+        )
+    }
+}
+
+/// Directory holding the settings file and the session cookie, `$XDG_CONFIG_HOME/aoc24`.
+pub fn config_dir() -> Option<PathBuf> {
+    let config_dir = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(config_dir.join("aoc24"))
+}
+
+fn config_path() -> Option<PathBuf> {
+    Some(config_dir()?.join("settings.conf"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Settings;
+
+    #[test]
+    pub fn round_trip() {
+        let settings = Settings {
+            inputs_dir: String::from("/home/user/aoc inputs"),
+            window_x: 10,
+            window_y: 20,
+            window_width: 800,
+            window_height: 600,
+            last_day: 4,
+            theme: String::from("dark"),
+        };
+
+        assert_eq!(Settings::parse(&settings.to_text()), settings);
+    }
+
+    #[test]
+    pub fn parse_partial() {
+        let settings = Settings::parse("# comment\nlast_day = 3\nwindow_x = left\nunknown = 1\n");
+
+        assert_eq!(settings.last_day, 3);
+        assert_eq!(settings.window_x, Settings::default().window_x);
+        assert_eq!(settings.theme, "light");
+    }
+}
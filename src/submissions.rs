@@ -0,0 +1,244 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::client::{Client, Verdict};
+use crate::utils::{is_single_star_day, save_expected_answer};
+
+/// An answer sent to the website, logged as a line of `{inputs_dir}/submissions/dayN.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: i32,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub submitted: u64,
+}
+
+impl Attempt {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        let attempt = Self {
+            submitted: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            answer: fields.next()?.to_string(),
+            verdict: fields.next()?.parse().ok()?,
+        };
+        Some(attempt)
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\n",
+            self.submitted, self.part, self.answer, self.verdict
+        )
+    }
+}
+
+/// Previous attempts for the given day, oldest first. Unreadable lines are skipped.
+pub fn load_attempts(day_number: i32, inputs_dir: &str) -> Vec<Attempt> {
+    std::fs::read_to_string(submissions_path(day_number, inputs_dir))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(Attempt::parse)
+        .collect()
+}
+
+/// Checks the answer against previous attempts, so it is only sent if it could be right.
+pub fn check_attempt(
+    attempts: &[Attempt],
+    part: i32,
+    answer: &str,
+    now: u64,
+) -> Result<(), String> {
+    if let Some(Attempt {
+        verdict: Verdict::Wait(seconds),
+        submitted,
+        ..
+    }) = attempts.last()
+    {
+        if submitted + seconds > now {
+            return Err(format!(
+                "wait {}s before submitting again",
+                submitted + seconds - now
+            ));
+        }
+    }
+
+    let number: Option<i64> = answer.parse().ok();
+    for attempt in attempts.iter().filter(|attempt| attempt.part == part) {
+        // how the answer compares to this attempt's, when both are numbers
+        let ordering = number
+            .zip(attempt.answer.parse::<i64>().ok())
+            .map(|(a, b)| a.cmp(&b));
+        match attempt.verdict {
+            Verdict::Correct => {
+                return Err(format!(
+                    "part {part} was already solved with {}",
+                    attempt.answer
+                ))
+            }
+            Verdict::AlreadySolved => return Err(format!("part {part} was already solved")),
+            Verdict::Wait(_) => continue,
+            verdict if attempt.answer == answer => {
+                return Err(format!("{answer} was already rejected as {verdict}"))
+            }
+            Verdict::TooHigh if ordering.is_some_and(|ordering| ordering.is_ge()) => {
+                return Err(format!(
+                    "{answer} is not lower than {}, which is too high",
+                    attempt.answer
+                ))
+            }
+            Verdict::TooLow if ordering.is_some_and(|ordering| ordering.is_le()) => {
+                return Err(format!(
+                    "{answer} is not higher than {}, which is too low",
+                    attempt.answer
+                ))
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Submits the answer unless a previous attempt rules it out, logs the attempt and keeps
+/// a correct answer as the expected one.
+pub fn submit(
+    client: &mut Client,
+    day_number: i32,
+    part: i32,
+    answer: &str,
+    inputs_dir: &str,
+) -> Result<Verdict, String> {
+    let parts = if is_single_star_day(day_number) { 1 } else { 2 };
+    if !(1..=25).contains(&day_number) || !(1..=parts).contains(&part) {
+        return Err(format!("day {day_number} has no part {part}"));
+    }
+    let answer = answer.trim();
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(format!("invalid answer \"{answer}\""));
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    check_attempt(&load_attempts(day_number, inputs_dir), part, answer, now)?;
+
+    let verdict = client.submit_answer(day_number, part, answer)?;
+    let attempt = Attempt {
+        part,
+        answer: answer.to_string(),
+        verdict,
+        submitted: now,
+    };
+    record_attempt(day_number, inputs_dir, &attempt)?;
+    if verdict == Verdict::Correct {
+        save_expected_answer(day_number, part, answer, inputs_dir)?;
+    }
+    Ok(verdict)
+}
+
+fn record_attempt(day_number: i32, inputs_dir: &str, attempt: &Attempt) -> Result<(), String> {
+    use std::io::Write;
+
+    let path = submissions_path(day_number, inputs_dir);
+    std::fs::create_dir_all(format!("{inputs_dir}/submissions"))
+        .and_then(|_| {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+        })
+        .and_then(|mut file| file.write_all(attempt.to_line().as_bytes()))
+        .map_err(|err| format!("{err}. \"{path}\""))
+}
+
+fn submissions_path(day_number: i32, inputs_dir: &str) -> String {
+    format!("{inputs_dir}/submissions/day{day_number}.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::client::{Client, Verdict};
+    use crate::stand_in::StandIn;
+    use crate::utils;
+
+    use super::{check_attempt, load_attempts, submit, Attempt};
+
+    fn attempt(part: i32, answer: &str, verdict: Verdict, submitted: u64) -> Attempt {
+        Attempt {
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted,
+        }
+    }
+
+    #[test]
+    pub fn known_answers() {
+        let attempts = vec![
+            attempt(1, "100", Verdict::TooHigh, 1000),
+            attempt(1, "10", Verdict::TooLow, 1100),
+            attempt(1, "abc", Verdict::Incorrect, 1200),
+        ];
+
+        assert!(check_attempt(&attempts, 1, "50", 2000).is_ok());
+        assert!(check_attempt(&attempts, 1, "100", 2000).is_err());
+        assert!(check_attempt(&attempts, 1, "150", 2000).is_err());
+        assert!(check_attempt(&attempts, 1, "5", 2000).is_err());
+        assert!(check_attempt(&attempts, 1, "abc", 2000).is_err());
+        assert!(check_attempt(&attempts, 2, "100", 2000).is_ok());
+
+        let attempts = vec![
+            attempt(1, "50", Verdict::Correct, 1000),
+            attempt(2, "60", Verdict::Wait(30), 1100),
+        ];
+        assert!(check_attempt(&attempts, 1, "50", 2000).is_err());
+        assert!(check_attempt(&attempts, 2, "60", 1120).is_err());
+        assert!(check_attempt(&attempts, 2, "60", 1130).is_ok());
+    }
+
+    #[test]
+    pub fn submit_and_record() {
+        let server = StandIn::start(|request| match request.body.as_str() {
+            "level=1&answer=42" => (200, String::from("<p>That's the right answer!</p>")),
+            _ => (
+                200,
+                String::from("<p>That's not the right answer; your answer is too low.</p>"),
+            ),
+        });
+        let dir = std::env::temp_dir().join(format!("aoc24-submissions-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let dir = dir.to_str().unwrap();
+        let mut client = Client::new(server.url(), "abc123").with_min_interval(Duration::ZERO);
+
+        let verdict =
+            submit(&mut client, 5, 1, "41", dir).expect("expected Ok(Verdict), got Err()");
+        assert_eq!(verdict, Verdict::TooLow);
+        assert!(submit(&mut client, 5, 1, "41", dir).is_err());
+        assert!(submit(&mut client, 5, 1, "40", dir).is_err());
+        let verdict =
+            submit(&mut client, 5, 1, "42", dir).expect("expected Ok(Verdict), got Err()");
+        assert_eq!(verdict, Verdict::Correct);
+        assert!(submit(&mut client, 5, 1, "43", dir).is_err());
+        assert!(submit(&mut client, 25, 2, "1", dir).is_err());
+        assert_eq!(server.requests().len(), 2);
+
+        let attempts: Vec<(String, Verdict)> = load_attempts(5, dir)
+            .into_iter()
+            .map(|attempt| (attempt.answer, attempt.verdict))
+            .collect();
+        assert_eq!(
+            attempts,
+            vec![
+                (String::from("41"), Verdict::TooLow),
+                (String::from("42"), Verdict::Correct)
+            ]
+        );
+        assert_eq!(
+            utils::get_expected_answers(5, dir),
+            Some(vec![String::from("42")])
+        );
+        std::fs::remove_dir_all(dir).expect("cannot clean up");
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 pub fn get_input(day_number: i32, inputs_dir: &str) -> Result<String, String>  {
     let input_path = format!("{inputs_dir}/day{day_number}.txt");
     match std::fs::read_to_string(&input_path) {
@@ -6,8 +8,174 @@ pub fn get_input(day_number: i32, inputs_dir: &str) -> Result<String, String>  {
     }
 }
 
-#[derive(Default)]
+/// Known answers for a day's input, one per line in `{inputs_dir}/answers/dayN.txt`.
+pub fn get_expected_answers(day_number: i32, inputs_dir: &str) -> Option<Vec<String>> {
+    let answers_path = format!("{inputs_dir}/answers/day{day_number}.txt");
+    let answers = std::fs::read_to_string(answers_path).ok()?;
+    Some(
+        answers
+            .lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect(),
+    )
+}
+
+/// Stores a confirmed answer as the expected answer of the given part, unless one is
+/// already known or the previous part's answer is still missing.
+pub fn save_expected_answer(
+    day_number: i32,
+    part: i32,
+    answer: &str,
+    inputs_dir: &str,
+) -> Result<(), String> {
+    let mut answers = get_expected_answers(day_number, inputs_dir).unwrap_or_default();
+    if answers.len() + 1 != part as usize {
+        return Ok(());
+    }
+    answers.push(answer.to_string());
+
+    let answers_dir = format!("{inputs_dir}/answers");
+    let answers_path = format!("{answers_dir}/day{day_number}.txt");
+    std::fs::create_dir_all(&answers_dir)
+        .and_then(|_| std::fs::write(&answers_path, answers.join("\n") + "\n"))
+        .map_err(|err| format!("{err}. \"{answers_path}\""))
+}
+
+/// Edited copies of a day's input, stored as `{inputs_dir}/variants/dayN-{name}.txt`.
+pub fn get_variant_names(day_number: i32, inputs_dir: &str) -> Vec<String> {
+    let prefix = format!("day{day_number}-");
+    let mut names: Vec<String> = std::fs::read_dir(format!("{inputs_dir}/variants"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|file_name| {
+            let name = file_name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+            Some(name.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+pub fn get_variant(day_number: i32, name: &str, inputs_dir: &str) -> Result<String, String> {
+    let variant_path = format!("{inputs_dir}/variants/day{day_number}-{name}.txt");
+    std::fs::read_to_string(&variant_path).map_err(|err| format!("{err}. \"{variant_path}\""))
+}
+
+pub fn save_variant(
+    day_number: i32,
+    name: &str,
+    inputs_dir: &str,
+    input: &str,
+) -> Result<(), String> {
+    let valid_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid_name {
+        return Err(format!(
+            "invalid variant name \"{name}\", use letters, digits, '-' and '_'"
+        ));
+    }
+
+    let variants_dir = format!("{inputs_dir}/variants");
+    let variant_path = format!("{variants_dir}/day{day_number}-{name}.txt");
+    std::fs::create_dir_all(&variants_dir)
+        .and_then(|_| std::fs::write(&variant_path, input))
+        .map_err(|err| format!("{err}. \"{variant_path}\""))
+}
+
+/// The last puzzle of the calendar only awards one star.
+pub fn is_single_star_day(day_number: i32) -> bool {
+    day_number == 25
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Default for Answer {
+    fn default() -> Self {
+        Answer::Number(0)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl TryFrom<u64> for Answer {
+    type Error = String;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        i64::try_from(value)
+            .map(Answer::Number)
+            .or(Err(format!("answer {value} is too large")))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl PartialEq<i32> for Answer {
+    fn eq(&self, other: &i32) -> bool {
+        matches!(self, Answer::Number(number) if *number == i64::from(*other))
+    }
+}
+
+impl PartialEq<u64> for Answer {
+    fn eq(&self, other: &u64) -> bool {
+        matches!(self, Answer::Number(number) if i64::try_from(*other) == Ok(*number))
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Answer::Text(text) if text == other)
+    }
+}
+
+pub trait Puzzle {
+    fn one_star_answer(&self) -> Result<Answer, String>;
+    fn two_star_answer(&self) -> Result<Answer, String>;
+
+    fn solve(&self) -> Result<Solution, String> {
+        Ok(Solution {
+            one_star_answer: self.one_star_answer()?,
+            two_star_answer: self.two_star_answer()?,
+        })
+    }
+}
+
+#[derive(Default, Clone)]
 pub struct Solution {
-    pub one_star_answer: i32,
-    pub two_star_answer: i32,
-}
\ No newline at end of file
+    pub one_star_answer: Answer,
+    pub two_star_answer: Answer,
+}
+
+impl Solution {
+    pub fn matches(&self, expected: &[String]) -> bool {
+        !expected.is_empty()
+            && expected
+                .iter()
+                .zip([&self.one_star_answer, &self.two_star_answer])
+                .all(|(expected, answer)| *expected == answer.to_string())
+    }
+}
@@ -0,0 +1,101 @@
+use std::io::ErrorKind;
+
+use inotify::{Events, Inotify, WatchMask};
+
+use crate::inputs::parse_day_number;
+
+/// Watches an inputs directory for `dayN.txt` files being written, created or replaced.
+pub struct InputsWatcher {
+    inotify: Inotify,
+    buffer: Vec<u8>,
+}
+
+impl InputsWatcher {
+    pub fn new(inputs_dir: &str) -> Result<Self, String> {
+        let inotify = Inotify::init().map_err(|err| format!("cannot start watching: {err}"))?;
+        // editors often save by writing a temporary file and renaming it over the input
+        inotify
+            .watches()
+            .add(inputs_dir, WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO)
+            .map_err(|err| format!("{err}. \"{inputs_dir}\""))?;
+
+        Ok(Self {
+            inotify,
+            buffer: vec![0; 4096],
+        })
+    }
+
+    /// Days whose input changed since the last call, without waiting.
+    pub fn changed_days(&mut self) -> Result<Vec<i32>, String> {
+        let mut days = Vec::new();
+        loop {
+            match self.inotify.read_events(&mut self.buffer) {
+                Ok(events) => collect_days(events, &mut days),
+                Err(err) if err.kind() == ErrorKind::WouldBlock => return Ok(days),
+                Err(err) => return Err(err.to_string()),
+            }
+        }
+    }
+
+    /// Blocks until the input of at least one day changes.
+    pub fn wait_for_changes(&mut self) -> Result<Vec<i32>, String> {
+        loop {
+            let mut days = Vec::new();
+            let events = self
+                .inotify
+                .read_events_blocking(&mut self.buffer)
+                .map_err(|err| err.to_string())?;
+            collect_days(events, &mut days);
+            days.extend(self.changed_days()?);
+            days.sort();
+            days.dedup();
+
+            if !days.is_empty() {
+                return Ok(days);
+            }
+        }
+    }
+}
+
+fn collect_days(events: Events, days: &mut Vec<i32>) {
+    for event in events {
+        let day_number = event
+            .name
+            .and_then(|name| name.to_str())
+            .and_then(parse_day_number);
+        if let Some(day_number) = day_number {
+            if !days.contains(&day_number) {
+                days.push(day_number);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputsWatcher;
+
+    #[test]
+    pub fn detect_changes() {
+        let inputs_dir = std::env::temp_dir().join(format!("aoc24-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&inputs_dir).expect("cannot create directory");
+        let mut watcher = InputsWatcher::new(inputs_dir.to_str().expect("path is not UTF-8"))
+            .expect("expected Ok(InputsWatcher), got Err()");
+
+        assert_eq!(watcher.changed_days(), Ok(vec![]));
+
+        std::fs::write(inputs_dir.join("day3.txt"), "mul(2,3)").expect("cannot write file");
+        std::fs::write(inputs_dir.join("notes.txt"), "todo").expect("cannot write file");
+        std::fs::write(inputs_dir.join("day3.txt"), "mul(2,4)").expect("cannot write file");
+        std::fs::write(inputs_dir.join("tmp"), "1 2").expect("cannot write file");
+        std::fs::rename(inputs_dir.join("tmp"), inputs_dir.join("day1.txt"))
+            .expect("cannot rename file");
+
+        assert_eq!(watcher.changed_days(), Ok(vec![3, 1]));
+
+        std::fs::write(inputs_dir.join("day4.txt"), "XMAS").expect("cannot write file");
+        assert_eq!(watcher.wait_for_changes(), Ok(vec![4]));
+
+        std::fs::remove_dir_all(&inputs_dir).expect("cannot clean up");
+    }
+}
@@ -0,0 +1,20 @@
+use aoc24::registry::get_parser;
+
+fn check_answer(day_number: i32, input_path: &str, part: i32, expected: &str) {
+    let parser = get_parser(day_number).expect("expected Ok(Parser), got Err()");
+    let input = std::fs::read_to_string(input_path).expect("Cannot get input");
+    let puzzle = parser(input).expect("expected Ok(Puzzle), got Err()");
+    let answer = match part {
+        1 => puzzle.one_star_answer(),
+        _ => puzzle.two_star_answer(),
+    };
+
+    assert_eq!(
+        answer.expect("expected Ok(Answer), got Err()").to_string(),
+        expected
+    );
+}
+
+// Day 20 has no sample file: its answers count the cheats saving at least 100 picoseconds and
+// the sample has none, so its sample is checked with smaller thresholds in day20.rs instead.
+include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));